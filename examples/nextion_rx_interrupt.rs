@@ -0,0 +1,78 @@
+#![no_std]
+#![no_main]
+
+use core::cell::RefCell;
+
+use cortex_m::interrupt::Mutex;
+use cortex_m_rt::entry;
+use gx_rust_nextion::nextion::rx::NextionRx;
+use gx_rust_nextion::nextion::Nextion;
+use heapless::spsc::Queue;
+use panic_halt as _;
+use stm32f1xx_hal::{
+    pac::{self, interrupt, USART3},
+    prelude::*,
+    serial::{Config, Event, Rx, Serial},
+};
+
+static RX: Mutex<RefCell<Option<NextionRx<'static, Rx<USART3>, 256>>>> =
+    Mutex::new(RefCell::new(None));
+
+#[entry]
+fn main() -> ! {
+    // Get access to the device specific peripherals from the peripheral access crate
+    let p = pac::Peripherals::take().unwrap();
+
+    // Take ownership over the raw flash and rcc devices and convert them into the corresponding
+    // HAL structs
+    let mut flash = p.FLASH.constrain();
+    let rcc = p.RCC.constrain();
+
+    // Freeze the configuration of all the clocks in the system and store the frozen frequencies in
+    // `clocks`
+    let clocks = rcc.cfgr.freeze(&mut flash.acr);
+
+    // Prepare the alternate function I/O registers
+    let mut afio = p.AFIO.constrain();
+
+    // Prepare the GPIOB peripheral
+    let mut gpiob = p.GPIOB.split();
+    // USART3
+    // Configure pb10 as a push_pull output, this will be the tx pin
+    let tx = gpiob.pb10.into_alternate_push_pull(&mut gpiob.crh);
+    // Take ownership over pb11
+    let rx = gpiob.pb11;
+
+    // Set up the usart device. Take ownership over the USART register and tx/rx pins. The rest of
+    // the registers are used to enable and configure the device.
+    let mut serial = Serial::new(
+        p.USART3,
+        (tx, rx),
+        &mut afio.mapr,
+        Config::default().baudrate(115200.bps()),
+        &clocks,
+    );
+    serial.listen(Event::Rxne);
+    let (tx, rx) = serial.split();
+
+    // Queue shared between the RX interrupt and the main loop
+    let queue = cortex_m::singleton!(: Queue<u8, 256> = Queue::new()).unwrap();
+
+    // The command side reads from the queue, the RX half is moved to the interrupt
+    let (mut nex, nex_rx) = Nextion::new_queued(tx, rx, queue);
+    cortex_m::interrupt::free(|cs| RX.borrow(cs).replace(Some(nex_rx)));
+    unsafe { pac::NVIC::unmask(pac::Interrupt::USART3) };
+
+    loop {
+        let _ = nex.poll_events(&mut []);
+    }
+}
+
+#[interrupt]
+fn USART3() {
+    cortex_m::interrupt::free(|cs| {
+        if let Some(rx) = RX.borrow(cs).borrow_mut().as_mut() {
+            let _ = rx.on_interrupt();
+        }
+    });
+}
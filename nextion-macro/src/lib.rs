@@ -4,6 +4,7 @@ use proc_macro2::Ident;
 use quote::quote;
 use syn::{parse_macro_input, Variant, punctuated::Punctuated, token::Comma, Attribute, parse::{Parse, ParseStream}};
 use syn::Token;
+use syn::ext::IdentExt;
 use syn::ItemEnum;
 
 #[proc_macro_attribute]
@@ -179,6 +180,19 @@ fn create_object_display(ident:&Ident,variants:&Punctuated<Variant,Comma>)->Toke
                     self.0.call_on_release();
                 }
             }
+            // commands of every display component
+            impl<'l, USART> NextionVis<USART> for #name_struct<'l, USART>
+            where
+                USART: embedded_hal::serial::Read<u8> + embedded_hal::blocking::serial::Write<u8>,{}
+            impl<'l, USART> NextionTsw<USART> for #name_struct<'l, USART>
+            where
+                USART: embedded_hal::serial::Read<u8> + embedded_hal::blocking::serial::Write<u8>,{}
+            impl<'l, USART> NextionRef<USART> for #name_struct<'l, USART>
+            where
+                USART: embedded_hal::serial::Read<u8> + embedded_hal::blocking::serial::Write<u8>,{}
+            impl<'l, USART> NextionClick<USART> for #name_struct<'l, USART>
+            where
+                USART: embedded_hal::serial::Read<u8> + embedded_hal::blocking::serial::Write<u8>,{}
             // impl
             #(#atr)*
 
@@ -312,7 +326,8 @@ impl Parse for NextionParser {
 
         let vec =strs.iter().map(|str|{
             // get name trait
-            let name_trait:Ident =input.parse().unwrap();
+            // `loop` is a keyword, attribute names are parsed as any ident
+            let name_trait:Ident =Ident::parse_any(input).unwrap();
             // if str containt `=`, parse value:
             let trait_value =if str.contains('=') {
                 let _:Token![=]=input.parse().unwrap();
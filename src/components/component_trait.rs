@@ -3,15 +3,19 @@ use core::fmt::Write;
 use heapless::String;
 use num_traits::NumCast;
 
-use crate::nextion::{ComError, IntoU8, NextionCom};
+use crate::nextion::{literal::StrLiteral, ComError, IntoU8, NextionCom};
 
 use super::{
     objects::{
-        NumberFormat, TextHorizontalAlignment, TextScrollDirection, TextType, TextVerticalAlignment,
+        MediaSource, NumberFormat, PlayState, TextHorizontalAlignment, TextScrollDirection,
+        TextType, TextVerticalAlignment,
     },
     BaseInfo, ObjInfo,
 };
 
+/// Maximum number of samples sent by one `addt`.
+pub const MAX_ADDT_LEN: usize = 1024;
+
 pub trait NextionTim<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
 where
     USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
@@ -41,7 +45,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -92,7 +96,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -118,6 +122,10 @@ where
     where
         Self: Sized,
     {
+        let txt = match StrLiteral::new(txt) {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
         let name = self.get_component_name();
         let mut cmd = String::<265>::new();
         match write!(cmd, "{}.txt={}", name, txt) {
@@ -140,13 +148,32 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
 
         self.get_str(str)
     }
+
+    /// Appends `txt` on the display with `t0.txt+="txt"`.
+    fn append_txt(&mut self, txt: &str) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let txt = match StrLiteral::new(txt) {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
+        let name = self.get_component_name();
+        let mut cmd = String::<265>::new();
+        match write!(cmd, "{}.txt+={}", name, txt) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
 }
 
 pub trait NextionBco<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
@@ -179,7 +206,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -227,7 +254,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -275,7 +302,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -323,7 +350,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -371,7 +398,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -419,7 +446,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -467,7 +494,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -515,7 +542,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -563,7 +590,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -612,7 +639,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -663,7 +690,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -718,7 +745,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -774,7 +801,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -822,7 +849,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -875,7 +902,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -923,7 +950,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -974,7 +1001,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1022,7 +1049,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1070,7 +1097,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1118,7 +1145,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1166,7 +1193,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1218,7 +1245,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1266,7 +1293,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1314,7 +1341,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1362,7 +1389,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1410,7 +1437,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1462,7 +1489,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1510,7 +1537,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1558,7 +1585,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1584,7 +1611,7 @@ where
     where
         Self: Sized,
     {
-        if channel > 4 {
+        if channel > 3 {
             return Err(ComError::InvalidDataRange);
         }
         let id = self.get_component_id();
@@ -1596,6 +1623,35 @@ where
 
         self.send_cmd(cmd.as_bytes())
     }
+
+    /// Adds `data` to `channel` with `addt`, streamed as transparent data in
+    /// chunks of [`MAX_ADDT_LEN`] samples.
+    fn add_bulk(&mut self, channel: u8, data: &[u8]) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        if channel > 3 {
+            return Err(ComError::InvalidDataRange);
+        }
+        match self.check_page() {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let id = self.get_component_id();
+        for chunk in data.chunks(MAX_ADDT_LEN) {
+            let mut cmd = String::<18>::new();
+            match write!(cmd, "addt {},{},{}", id, channel, chunk.len()) {
+                Ok(_) => {}
+                Err(_) => return Err(ComError::FailedCreateCommand),
+            };
+
+            match self.get_device().send_transparent(cmd.as_bytes(), chunk) {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+        }
+        Ok(())
+    }
 }
 
 pub trait NextionCle<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
@@ -1755,7 +1811,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1806,7 +1862,7 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1857,7 +1913,103 @@ where
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
 
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        let mut buff = match NumCast::from(0) {
+            Some(x) => x,
+            None => return Err(ComError::FailedCreateNumberBuffer),
+        };
+
+        match self.get_number::<u8>(&mut buff) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+        Ok(buff)
+    }
+}
+
+pub trait NextionVid<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    /// Sets the video or audio resource id.
+    fn set_vid(&mut self, vid: u16) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "{}.vid={}", name, vid) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn get_vid(&mut self) -> Result<u16, ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "get {}.vid", name) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        match self.send_query(cmd.as_bytes()) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        let mut buff = match NumCast::from(0) {
+            Some(x) => x,
+            None => return Err(ComError::FailedCreateNumberBuffer),
+        };
+
+        match self.get_number::<u16>(&mut buff) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+        Ok(buff)
+    }
+}
+
+pub trait NextionMediaEn<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    /// Stops, plays or pauses the video or audio.
+    fn set_media_en(&mut self, en: PlayState) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<23>::new();
+        match write!(cmd, "{}.en={}", name, en.into_u8()) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn get_media_en(&mut self) -> Result<PlayState, ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<23>::new();
+        match write!(cmd, "get {}.en", name) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => (),
             Err(err) => return Err(err),
         };
@@ -1871,6 +2023,328 @@ where
             Ok(_) => (),
             Err(err) => return Err(err),
         };
+        PlayState::from_u8(buff)
+    }
+}
+
+pub trait NextionMediaTim<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    /// Seeks the video or audio to `tim` milliseconds.
+    fn set_media_tim(&mut self, tim: u32) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<32>::new();
+        match write!(cmd, "{}.tim={}", name, tim) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    /// Gets the playback position in milliseconds.
+    fn get_media_tim(&mut self) -> Result<u32, ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "get {}.tim", name) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        match self.send_query(cmd.as_bytes()) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        let mut buff = match NumCast::from(0) {
+            Some(x) => x,
+            None => return Err(ComError::FailedCreateNumberBuffer),
+        };
+
+        match self.get_number::<u32>(&mut buff) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
         Ok(buff)
     }
-}
\ No newline at end of file
+}
+
+pub trait NextionLoop<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    fn set_loop(&mut self, enable: bool) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "{}.loop={}", name, enable as u8) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn get_loop(&mut self) -> Result<bool, ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "get {}.loop", name) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        match self.send_query(cmd.as_bytes()) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        let mut buff = match NumCast::from(0) {
+            Some(x) => x,
+            None => return Err(ComError::FailedCreateNumberBuffer),
+        };
+
+        match self.get_number::<u8>(&mut buff) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+        match buff {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(ComError::InvalidValue),
+        }
+    }
+}
+
+pub trait NextionFrom<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    /// Plays the `vid` resource or the file at `path`.
+    fn set_from(&mut self, from: MediaSource) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "{}.from={}", name, from.into_u8()) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn get_from(&mut self) -> Result<MediaSource, ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "get {}.from", name) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        match self.send_query(cmd.as_bytes()) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        let mut buff = match NumCast::from(0) {
+            Some(x) => x,
+            None => return Err(ComError::FailedCreateNumberBuffer),
+        };
+
+        match self.get_number::<u8>(&mut buff) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+        MediaSource::from_u8(buff)
+    }
+}
+
+pub trait NextionPath<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    /// Sets the external file played, like `sd0/intro.video`.
+    fn set_path(&mut self, path: &str) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let path = match StrLiteral::new(path) {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
+        let name = self.get_component_name();
+        let mut cmd = String::<265>::new();
+        match write!(cmd, "{}.path={}", name, path) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn get_path(&mut self, str: &mut [u8]) -> Result<u8, ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "get {}.path", name) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        match self.send_query(cmd.as_bytes()) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        self.get_str(str)
+    }
+}
+
+pub trait NextionVol<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    /// Sets the volume from 0 to 100.
+    fn set_vol(&mut self, vol: u8) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        if vol > 100 {
+            return Err(ComError::InvalidDataRange);
+        }
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "{}.vol={}", name, vol) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn get_vol(&mut self) -> Result<u8, ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "get {}.vol", name) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        match self.send_query(cmd.as_bytes()) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+
+        let mut buff = match NumCast::from(0) {
+            Some(x) => x,
+            None => return Err(ComError::FailedCreateNumberBuffer),
+        };
+
+        match self.get_number::<u8>(&mut buff) {
+            Ok(_) => (),
+            Err(err) => return Err(err),
+        };
+        Ok(buff)
+    }
+}
+
+pub trait NextionVis<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    /// Shows or hides the component with `vis`.
+    fn set_visible(&mut self, visible: bool) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "vis {},{}", name, visible as u8) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+}
+
+pub trait NextionTsw<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    /// Enables or disables touch events of the component with `tsw`.
+    fn set_touch(&mut self, enable: bool) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "tsw {},{}", name, enable as u8) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+}
+
+pub trait NextionRef<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    /// Redraws the component with `ref`.
+    fn refresh(&mut self) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<22>::new();
+        match write!(cmd, "ref {}", name) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+}
+
+pub trait NextionClick<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::blocking::serial::Write<u8> + embedded_hal::serial::Read<u8>,
+{
+    /// Runs the press or release event code of the component with `click`.
+    fn click(&mut self, pressed: bool) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "click {},{}", name, pressed as u8) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+
+        self.send_cmd(cmd.as_bytes())
+    }
+}
@@ -1,10 +1,10 @@
 use core::fmt::{Display, Write};
 
-use crate::nextion::{ComError, Nextion, NextionCom};
+use crate::nextion::{literal::StrLiteral, ComError, Nextion, NextionCom};
 
 use heapless::String;
 use nextion_macro::object_builder;
-use num_traits::{NumCast, PrimInt};
+use num_traits::{NumCast, PrimInt, Zero};
 
 #[object_builder]
 #[derive(Clone, Copy)]
@@ -56,6 +56,10 @@ pub enum NextionObjectDisplay {
     TouchCap,
     #[nextion(txt, dis, bco, pco)]
     QRCode,
+    #[nextion(vid, en = media, tim = media, loop, from, path, dis, vol)]
+    Video,
+    #[nextion(vid, en = media, tim = media, vol, loop)]
+    Audio,
 }
 
 // pub trait ObjectTypes {}
@@ -95,7 +99,7 @@ where
             Ok(_) => {}
             Err(_) => return Err(ComError::FailedCreateCommand),
         };
-        match self.send_cmd(cmd.as_bytes()) {
+        match self.send_query(cmd.as_bytes()) {
             Ok(_) => {}
             Err(err) => return Err(err),
         };
@@ -112,6 +116,137 @@ where
 
         Ok(buff)
     }
+
+    /// Adds `value` on the display with `n0.val+=value`.
+    fn add_assign(&mut self, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        self.compound_assign("+=", value)
+    }
+
+    /// Subtracts `value` on the display with `n0.val-=value`.
+    fn sub_assign(&mut self, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        self.compound_assign("-=", value)
+    }
+
+    /// Multiplies by `value` on the display with `n0.val*=value`.
+    fn mul_assign(&mut self, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        self.compound_assign("*=", value)
+    }
+
+    /// Divides by `value` on the display with `n0.val/=value`.
+    fn div_assign(&mut self, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        if value.is_zero() {
+            return Err(ComError::InvalidDataRange);
+        }
+        self.compound_assign("/=", value)
+    }
+
+    /// Keeps the remainder of the division by `value` with
+    /// `n0.val=n0.val%value`.
+    fn rem_assign(&mut self, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        if value.is_zero() {
+            return Err(ComError::InvalidDataRange);
+        }
+        self.expanded_assign("%", value)
+    }
+
+    /// Bitwise and with `n0.val=n0.val&value`.
+    fn bitand_assign(&mut self, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        self.expanded_assign("&", value)
+    }
+
+    /// Bitwise or with `n0.val=n0.val|value`.
+    fn bitor_assign(&mut self, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        self.expanded_assign("|", value)
+    }
+
+    /// Bitwise xor with `n0.val=n0.val^value`.
+    fn bitxor_assign(&mut self, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        self.expanded_assign("^", value)
+    }
+
+    /// Shifts left with `n0.val=n0.val<<value`.
+    fn shl_assign(&mut self, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        self.expanded_assign("<<", value)
+    }
+
+    /// Shifts right with `n0.val=n0.val>>value`.
+    fn shr_assign(&mut self, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        self.expanded_assign(">>", value)
+    }
+
+    /// Copies the value of `source` on the display with `n0.val=h0.val`.
+    fn set_value_from<S>(&mut self, source: &S) -> Result<(), ComError>
+    where
+        Self: Sized,
+        S: NextionVal<USART>,
+    {
+        let name = self.get_component_name();
+        // two names of 16 bytes
+        let mut cmd = String::<41>::new();
+        match write!(&mut cmd, "{}.val={}.val", name, source.get_component_name()) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn compound_assign(&mut self, op: &str, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        // name of 16 bytes, operator and `i32::MIN`
+        let mut cmd = String::<33>::new();
+        match write!(&mut cmd, "{}.val{}{}", name, op, value) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn expanded_assign(&mut self, op: &str, value: Self::ValueType) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let name = self.get_component_name();
+        // name of 16 bytes twice, operator and `i32::MIN`
+        let mut cmd = String::<54>::new();
+        match write!(&mut cmd, "{}.val={}.val{}{}", name, name, op, value) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_cmd(cmd.as_bytes())
+    }
 }
 
 pub trait NextionAct<USART>: NextionVal<USART, ValueType = u8>
@@ -148,6 +283,117 @@ where
     }
 }
 
+/// Value of an attribute set with [`NextionAttr::set_attr`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AttrValue<'a> {
+    Int(i32),
+    /// Sent as a [`StrLiteral`].
+    Str(&'a str),
+}
+
+impl Display for AttrValue<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AttrValue::Int(x) => write!(f, "{}", x),
+            AttrValue::Str(x) => match StrLiteral::new(x) {
+                Ok(x) => write!(f, "{}", x),
+                Err(_) => Err(core::fmt::Error),
+            },
+        }
+    }
+}
+
+/// Access to any attribute by name, for the attributes without a typed
+/// trait like `aph`, `effect` or `drag`.
+pub trait NextionAttr<USART>: NextionCom<USART> + BaseInfo + ObjInfo<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + embedded_hal::blocking::serial::Write<u8>,
+{
+    fn set_attr(&mut self, attr: &str, value: AttrValue) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        match check_attr_name(attr) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        if let AttrValue::Str(x) = value {
+            match StrLiteral::new(x) {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+        }
+        let name = self.get_component_name();
+        let mut cmd = String::<265>::new();
+        match write!(&mut cmd, "{}.{}={}", name, attr, value) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn get_attr_num(&mut self, attr: &str) -> Result<i32, ComError>
+    where
+        Self: Sized,
+    {
+        match self.send_get_attr(attr) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut buff = 0i32;
+        match self.get_number(&mut buff) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        Ok(buff)
+    }
+
+    fn get_attr_str(&mut self, attr: &str, buff: &mut [u8]) -> Result<u8, ComError>
+    where
+        Self: Sized,
+    {
+        match self.send_get_attr(attr) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.get_str(buff)
+    }
+
+    fn send_get_attr(&mut self, attr: &str) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        match check_attr_name(attr) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let name = self.get_component_name();
+        let mut cmd = String::<48>::new();
+        match write!(&mut cmd, "get {}.{}", name, attr) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_query(cmd.as_bytes())
+    }
+}
+
+impl<T, USART> NextionAttr<USART> for T
+where
+    T: NextionCom<USART> + BaseInfo + ObjInfo<USART>,
+    USART: embedded_hal::serial::Read<u8> + embedded_hal::blocking::serial::Write<u8>,
+{
+}
+
+/// Attribute names are sent as is, only letters, digits and `_` are
+/// accepted.
+fn check_attr_name(attr: &str) -> Result<(), ComError> {
+    let valid = !attr.is_empty() && attr.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'_');
+    match valid {
+        true => Ok(()),
+        false => Err(ComError::InvalidValue),
+    }
+}
+
 pub trait BaseInfo {
     /// Get page id.
     fn get_page_id(&self) -> u8;
@@ -185,3 +431,46 @@ impl BaseInfo for NextionBaseObj<'_> {
 }
 pub mod component_trait;
 pub mod objects;
+
+#[cfg(test)]
+mod tests {
+    use super::{nextion_object_display::Number, *};
+    use crate::nextion::tests::{nextion, MockSerial};
+
+    const NAME: &str = "abcdefghijklmnop";
+
+    /// Returns the last command sent by `obj` and forgets it.
+    fn sent<O: ObjInfo<MockSerial>>(obj: &mut O) -> String<64> {
+        let tx = &mut obj.get_device().get_peripheral().borrow_mut().tx;
+        let cmd = core::str::from_utf8(tx.strip_suffix(&[0xFF, 0xFF, 0xFF]).unwrap())
+            .unwrap()
+            .into();
+        tx.clear();
+        cmd
+    }
+
+    #[test]
+    fn value_commands_with_long_names() {
+        let mut nextion = nextion();
+        let source = Number::bind(&mut nextion, 0, 1, "ABCDEFGHIJKLMNOP");
+        let mut number = Number::bind(&mut nextion, 0, 2, NAME);
+
+        number.add_assign(i32::MIN).unwrap();
+        assert_eq!(sent(&mut number), "abcdefghijklmnop.val+=-2147483648");
+        number.bitand_assign(i32::MIN).unwrap();
+        assert_eq!(
+            sent(&mut number),
+            "abcdefghijklmnop.val=abcdefghijklmnop.val&-2147483648"
+        );
+        number.shl_assign(i32::MIN).unwrap();
+        assert_eq!(
+            sent(&mut number),
+            "abcdefghijklmnop.val=abcdefghijklmnop.val<<-2147483648"
+        );
+        number.set_value_from(&source).unwrap();
+        assert_eq!(
+            sent(&mut number),
+            "abcdefghijklmnop.val=ABCDEFGHIJKLMNOP.val"
+        );
+    }
+}
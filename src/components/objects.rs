@@ -115,6 +115,52 @@ impl IntoU8 for TextVerticalAlignment {
     }
 }
 
+/// Playback state of a video, an audio component or an audio channel.
+pub enum PlayState {
+    Stop,
+    Play,
+    Pause,
+}
+
+impl IntoU8 for PlayState {
+    fn into_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+impl PlayState {
+    pub fn from_u8(data: u8) -> Result<Self, ComError> {
+        match data {
+            0 => Ok(Self::Stop),
+            1 => Ok(Self::Play),
+            2 => Ok(Self::Pause),
+            _ => Err(ComError::InvalidValue),
+        }
+    }
+}
+
+/// Source of a video, a resource of the HMI or an external file.
+pub enum MediaSource {
+    Internal,
+    External,
+}
+
+impl IntoU8 for MediaSource {
+    fn into_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+impl MediaSource {
+    pub fn from_u8(data: u8) -> Result<Self, ComError> {
+        match data {
+            0 => Ok(Self::Internal),
+            1 => Ok(Self::External),
+            _ => Err(ComError::InvalidValue),
+        }
+    }
+}
+
 pub struct NextionObject<'l, USART> {
     base: NextionBaseObj<'l>,
     device: *mut Nextion<USART>,
@@ -175,6 +221,11 @@ pub trait TouchHandler<'l> {
     fn call_on_release(&mut self);
 }
 
+/// Component that can receive touch events from [`Nextion::poll_events`].
+pub trait TouchComponent<'l>: TouchHandler<'l> + BaseInfo {}
+
+impl<'l, T> TouchComponent<'l> for T where T: TouchHandler<'l> + BaseInfo {}
+
 pub struct NextionObjectDisplay<'l, USART> {
     base: NextionBaseObj<'l>,
     device: *mut Nextion<USART>,
@@ -218,7 +269,7 @@ where
     }
 
     fn call_on_release(&mut self) {
-        match &mut self.on_click {
+        match &mut self.on_release {
             Some(x) => {
                 (x)();
             }
@@ -0,0 +1,48 @@
+use core::fmt::Write;
+
+use embedded_hal::blocking::serial as blocking;
+use heapless::String;
+
+use super::{ComError, IntoU8, Nextion};
+use crate::components::objects::PlayState;
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Plays the audio resource `resid` on `channel` 0 or 1 with
+    /// `play ch,resid,loop`.
+    pub fn play(&mut self, channel: u8, resid: u16, looped: bool) -> Result<(), ComError> {
+        if channel > 1 {
+            return Err(ComError::InvalidDataRange);
+        }
+        let mut cmd = String::<20>::new();
+        match write!(cmd, "play {},{},{}", channel, resid, looped as u8) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    /// Stops, resumes or pauses audio `channel` 0 or 1 with `audio0` or
+    /// `audio1`.
+    pub fn set_audio(&mut self, channel: u8, state: PlayState) -> Result<(), ComError> {
+        match channel {
+            0 => self.set_sys_var("audio0", state.into_u8()),
+            1 => self.set_sys_var("audio1", state.into_u8()),
+            _ => Err(ComError::InvalidDataRange),
+        }
+    }
+
+    pub fn get_audio(&mut self, channel: u8) -> Result<PlayState, ComError> {
+        let res = match channel {
+            0 => self.get_sys_var("audio0"),
+            1 => self.get_sys_var("audio1"),
+            _ => return Err(ComError::InvalidDataRange),
+        };
+        match res {
+            Ok(x) => PlayState::from_u8(x),
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use core::fmt::Write;
+
+use embedded_hal::blocking::serial as blocking;
+use heapless::String;
+
+use super::{device::DeviceInfo, rx::QueuedSerial, ComError, Nextion, ReadTimeout};
+
+/// Baud rates accepted by `baud` and `bauds`, in the order tried by
+/// [`Nextion::detect_baud`].
+pub const STANDARD_BAUDS: [u32; 13] = [
+    9600, 115200, 2400, 4800, 19200, 31250, 38400, 57600, 230400, 250000, 256000, 512000, 921600,
+];
+
+/// Serial peripheral whose baud rate can be changed at runtime.
+pub trait BaudConfigurable {
+    fn set_baud_rate(&mut self, baud: u32) -> Result<(), ComError>;
+}
+
+impl<'a, TX, const N: usize> BaudConfigurable for QueuedSerial<'a, TX, N>
+where
+    TX: BaudConfigurable,
+{
+    fn set_baud_rate(&mut self, baud: u32) -> Result<(), ComError> {
+        self.get_tx().set_baud_rate(baud)
+    }
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Sends `baud=`, the display uses `baud` until it is powered off.
+    ///
+    /// The peripheral is not reconfigured, see [`Nextion::change_baud`].
+    pub fn set_baud(&mut self, baud: u32) -> Result<(), ComError> {
+        self.send_baud("baud", baud)
+    }
+
+    /// Sends `bauds=`, the display keeps `baud` as its power on baud rate.
+    ///
+    /// The peripheral is not reconfigured, see [`Nextion::change_baud`].
+    pub fn set_bauds(&mut self, baud: u32) -> Result<(), ComError> {
+        self.send_baud("bauds", baud)
+    }
+
+    fn send_baud(&mut self, var: &str, baud: u32) -> Result<(), ComError> {
+        if !STANDARD_BAUDS.contains(&baud) {
+            return Err(ComError::InvalidDataRange);
+        }
+        let mut cmd = String::<14>::new();
+        match write!(cmd, "{}={}", var, baud) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        // the answer, if any, is sent with the new baud rate
+        self.send_query(cmd.as_bytes())
+    }
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8> + BaudConfigurable,
+{
+    /// Switches the display to `baud`, persisted over power cycles when
+    /// `persist` is set, then reconfigures the peripheral to match.
+    pub fn change_baud(&mut self, baud: u32, persist: bool) -> Result<(), ComError> {
+        let res = match persist {
+            true => self.set_bauds(baud),
+            false => self.set_baud(baud),
+        };
+        match res {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        match self.get_peripheral().borrow_mut().set_baud_rate(baud) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.clear_input()
+    }
+
+    /// Tries every rate of [`STANDARD_BAUDS`] until `connect` succeeds and
+    /// leaves the peripheral at that rate.
+    ///
+    /// A finite [`ReadTimeout`] must be set, otherwise
+    /// [`ComError::InvalidValue`] is returned.
+    pub fn detect_baud(&mut self) -> Result<(u32, DeviceInfo), ComError> {
+        if let ReadTimeout::Infinite = self.get_read_timeout() {
+            return Err(ComError::InvalidValue);
+        }
+
+        for baud in STANDARD_BAUDS {
+            match self.get_peripheral().borrow_mut().set_baud_rate(baud) {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+            match self.clear_input() {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+            if let Ok(info) = self.connect() {
+                return Ok((baud, info));
+            }
+        }
+        Err(ComError::Timeout)
+    }
+}
@@ -0,0 +1,293 @@
+use core::fmt::Write;
+
+use embedded_hal::blocking::serial as blocking;
+use heapless::String;
+
+use super::{literal::StrLiteral, ComError, IntoU8, Nextion};
+use crate::components::objects::{TextHorizontalAlignment, TextVerticalAlignment};
+
+/// Background drawn behind the text of [`Canvas::xstr`], the `sta` argument.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TextBackground {
+    /// Crop of the picture with this id at the same position.
+    Crop(u16),
+    /// Solid color.
+    Color(u16),
+    /// Picture with this id.
+    Image(u16),
+    /// Nothing, the text is drawn over the screen content.
+    None,
+}
+
+impl TextBackground {
+    /// Returns the `sta` and `bco` arguments of `xstr`.
+    fn args(self) -> (u8, u16) {
+        match self {
+            Self::Crop(pic) => (0, pic),
+            Self::Color(color) => (1, color),
+            Self::Image(pic) => (2, pic),
+            Self::None => (3, 0),
+        }
+    }
+}
+
+/// Drawing commands over the screen, returned by [`Nextion::canvas`].
+///
+/// Coordinates are checked against the screen size given to
+/// [`Nextion::canvas`], [`ComError::InvalidDataRange`] is returned for any
+/// shape that does not fit. Colors are RGB565.
+pub struct Canvas<'a, USART> {
+    pub(crate) nextion: &'a mut Nextion<USART>,
+    pub(crate) width: u16,
+    pub(crate) height: u16,
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Returns the drawing commands for a screen of `width` by `height`
+    /// pixels, in the orientation of the HMI project.
+    pub fn canvas(&mut self, width: u16, height: u16) -> Canvas<'_, USART> {
+        Canvas {
+            nextion: self,
+            width,
+            height,
+        }
+    }
+}
+
+impl<'a, USART> Canvas<'a, USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Clears the screen with `color`, `cls`.
+    pub fn cls(&mut self, color: u16) -> Result<(), ComError> {
+        let mut cmd = String::<10>::new();
+        match write!(cmd, "cls {}", color) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    /// Fills a rectangle with `color`, `fill`.
+    pub fn fill(&mut self, x: u16, y: u16, w: u16, h: u16, color: u16) -> Result<(), ComError> {
+        match self.check_rect(x, y, w, h) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<36>::new();
+        match write!(cmd, "fill {},{},{},{},{}", x, y, w, h, color) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    /// Draws a line from `(x1, y1)` to `(x2, y2)`, `line`.
+    pub fn line(&mut self, x1: u16, y1: u16, x2: u16, y2: u16, color: u16) -> Result<(), ComError> {
+        self.shape("line", x1, y1, x2, y2, color)
+    }
+
+    /// Draws the outline of the rectangle from `(x1, y1)` to `(x2, y2)`,
+    /// `draw`.
+    pub fn draw(&mut self, x1: u16, y1: u16, x2: u16, y2: u16, color: u16) -> Result<(), ComError> {
+        self.shape("draw", x1, y1, x2, y2, color)
+    }
+
+    /// Draws the outline of a circle, `cir`.
+    pub fn cir(&mut self, x: u16, y: u16, r: u16, color: u16) -> Result<(), ComError> {
+        self.circle("cir", x, y, r, color)
+    }
+
+    /// Draws a filled circle, `cirs`.
+    pub fn cirs(&mut self, x: u16, y: u16, r: u16, color: u16) -> Result<(), ComError> {
+        self.circle("cirs", x, y, r, color)
+    }
+
+    /// Prints `txt` in the area `x, y, w, h` with `font` and color `pco`,
+    /// `xstr`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn xstr(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        font: u8,
+        pco: u16,
+        background: TextBackground,
+        xcen: TextHorizontalAlignment,
+        ycen: TextVerticalAlignment,
+        txt: &str,
+    ) -> Result<(), ComError> {
+        match self.check_rect(x, y, w, h) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let txt = match StrLiteral::new(txt) {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
+        let (sta, bco) = background.args();
+        let mut cmd = String::<265>::new();
+        match write!(
+            cmd,
+            "xstr {},{},{},{},{},{},{},{},{},{},{}",
+            x,
+            y,
+            w,
+            h,
+            font,
+            pco,
+            bco,
+            xcen.into_u8(),
+            ycen.into_u8(),
+            sta,
+            txt
+        ) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    /// Draws the picture `id` at `(x, y)`, `pic`.
+    pub fn pic(&mut self, x: u16, y: u16, id: u16) -> Result<(), ComError> {
+        match self.check_point(x, y) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "pic {},{},{}", x, y, id) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    /// Draws the area `x, y, w, h` of the picture `id` at the same position,
+    /// `picq`.
+    pub fn picq(&mut self, x: u16, y: u16, w: u16, h: u16, id: u16) -> Result<(), ComError> {
+        match self.check_rect(x, y, w, h) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<36>::new();
+        match write!(cmd, "picq {},{},{},{},{}", x, y, w, h, id) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    /// Draws the area of the picture `id` starting at `(x0, y0)` into the
+    /// area `x, y, w, h`, `xpic`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn xpic(
+        &mut self,
+        x: u16,
+        y: u16,
+        w: u16,
+        h: u16,
+        x0: u16,
+        y0: u16,
+        id: u16,
+    ) -> Result<(), ComError> {
+        match self.check_rect(x, y, w, h) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<48>::new();
+        match write!(cmd, "xpic {},{},{},{},{},{},{}", x, y, w, h, x0, y0, id) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    fn shape(
+        &mut self,
+        op: &str,
+        x1: u16,
+        y1: u16,
+        x2: u16,
+        y2: u16,
+        color: u16,
+    ) -> Result<(), ComError> {
+        match self.check_point(x1, y1) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        match self.check_point(x2, y2) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<36>::new();
+        match write!(cmd, "{} {},{},{},{},{}", op, x1, y1, x2, y2, color) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    fn circle(&mut self, op: &str, x: u16, y: u16, r: u16, color: u16) -> Result<(), ComError> {
+        // the whole circle, from `x - r` to `x + r`, is on the screen
+        let fits = r <= x
+            && r <= y
+            && x as u32 + (r as u32) < self.width as u32
+            && y as u32 + (r as u32) < self.height as u32;
+        if !fits {
+            return Err(ComError::InvalidDataRange);
+        }
+        let mut cmd = String::<30>::new();
+        match write!(cmd, "{} {},{},{},{}", op, x, y, r, color) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    fn check_point(&self, x: u16, y: u16) -> Result<(), ComError> {
+        match x < self.width && y < self.height {
+            true => Ok(()),
+            false => Err(ComError::InvalidDataRange),
+        }
+    }
+
+    fn check_rect(&self, x: u16, y: u16, w: u16, h: u16) -> Result<(), ComError> {
+        let fits =
+            x as u32 + w as u32 <= self.width as u32 && y as u32 + h as u32 <= self.height as u32;
+        match fits {
+            true => Ok(()),
+            false => Err(ComError::InvalidDataRange),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nextion::tests::nextion;
+
+    #[test]
+    fn circles_fit_on_screen() {
+        let mut nextion = nextion();
+        let mut canvas = nextion.canvas(100, 50);
+        assert_eq!(canvas.cir(10, 10, 10, 0), Ok(()));
+        assert_eq!(canvas.cirs(89, 39, 10, 0), Ok(()));
+        assert_eq!(canvas.cir(9, 20, 10, 0), Err(ComError::InvalidDataRange));
+        assert_eq!(canvas.cir(20, 9, 10, 0), Err(ComError::InvalidDataRange));
+        assert_eq!(canvas.cirs(90, 20, 10, 0), Err(ComError::InvalidDataRange));
+        assert_eq!(canvas.cirs(50, 40, 10, 0), Err(ComError::InvalidDataRange));
+    }
+}
@@ -0,0 +1,197 @@
+use core::str::from_utf8;
+
+use embedded_hal::blocking::serial as blocking;
+use heapless::String;
+
+use super::{frame::Frame, ComError, Nextion, Wait};
+
+/// Display series, read from the model name.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NextionSeries {
+    Basic,
+    Discovery,
+    Enhanced,
+    Intelligent,
+    Unknown,
+}
+
+/// Device identification returned by `connect`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct DeviceInfo {
+    /// Display has a touch panel.
+    pub touch: bool,
+    pub reserved: String<16>,
+    /// Model name, like `NX4832T035_011R`.
+    pub model: String<24>,
+    pub firmware: u16,
+    pub mcu_code: u32,
+    pub serial: String<24>,
+    /// Flash size in bytes.
+    pub flash_size: u32,
+}
+
+impl DeviceInfo {
+    /// Parses the fields of a `comok` answer, as in
+    /// `1,30601-0,NX4832T035_011R,52,61488,D264B8204F0E1828,16777216`.
+    pub fn parse(data: &[u8]) -> Result<Self, ComError> {
+        let data = match from_utf8(data) {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::IvalidGetDataString),
+        };
+
+        let mut fields = [""; 7];
+        let mut split = data.split(',');
+        for field in fields.iter_mut() {
+            *field = match split.next() {
+                Some(x) => x.trim(),
+                None => return Err(ComError::IvalidGetDataString),
+            };
+        }
+
+        let touch = match fields[0] {
+            "0" => false,
+            "1" => true,
+            _ => return Err(ComError::InvalidValue),
+        };
+        let reserved = match fields[1].parse() {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
+        let model = match fields[2].parse() {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
+        let firmware = match fields[3].parse() {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
+        let mcu_code = match fields[4].parse() {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
+        let serial = match fields[5].parse() {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
+        let flash_size = match fields[6].parse() {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
+
+        Ok(DeviceInfo {
+            touch,
+            reserved,
+            model,
+            firmware,
+            mcu_code,
+            serial,
+            flash_size,
+        })
+    }
+
+    /// Returns the series from the letter following the screen size in the
+    /// model name, `T` in `NX4832T035`.
+    pub fn series(&self) -> NextionSeries {
+        let letter = self
+            .model
+            .chars()
+            .skip_while(|x| x.is_ascii_alphabetic())
+            .find(|x| !x.is_ascii_digit());
+        match letter {
+            Some('T') => NextionSeries::Basic,
+            Some('F') => NextionSeries::Discovery,
+            Some('K') => NextionSeries::Enhanced,
+            Some('P') => NextionSeries::Intelligent,
+            _ => NextionSeries::Unknown,
+        }
+    }
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Sends `connect` and returns the identification of the display.
+    pub fn connect(&mut self) -> Result<DeviceInfo, ComError> {
+        // terminate anything left in the display input buffer
+        match self.send_query(b"") {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        match self.send_query(b"connect") {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+
+        loop {
+            let res = self.next_frame(Wait::Timeout, |frame| match frame {
+                Frame::Comok(data) => DeviceInfo::parse(data).map(Some),
+                // answer to the empty instruction
+                Frame::ReturnCode(_) => Ok(None),
+                _ => Err(ComError::IvalidGetDataString),
+            });
+            match res {
+                Ok(Some(Some(info))) => return Ok(info),
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &[u8] = b"1,30601-0,NX4832T035_011R,52,61488,D264B8204F0E1828,16777216";
+
+    #[test]
+    fn parse_sample() {
+        let info = DeviceInfo::parse(SAMPLE).unwrap();
+        assert!(info.touch);
+        assert_eq!(info.reserved, "30601-0");
+        assert_eq!(info.model, "NX4832T035_011R");
+        assert_eq!(info.firmware, 52);
+        assert_eq!(info.mcu_code, 61488);
+        assert_eq!(info.serial, "D264B8204F0E1828");
+        assert_eq!(info.flash_size, 16777216);
+        assert_eq!(info.series(), NextionSeries::Basic);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_eq!(
+            DeviceInfo::parse(b"1,30601-0,NX4832T035_011R,52,61488,D264B8204F0E1828"),
+            Err(ComError::IvalidGetDataString)
+        );
+        assert_eq!(
+            DeviceInfo::parse(b"2,30601-0,NX4832T035_011R,52,61488,D264B8204F0E1828,16777216"),
+            Err(ComError::InvalidValue)
+        );
+        assert_eq!(
+            DeviceInfo::parse(b"0,30601-0,NX4832T035_011R,x,61488,D264B8204F0E1828,16777216"),
+            Err(ComError::InvalidValue)
+        );
+        assert_eq!(
+            DeviceInfo::parse(b"0,30601-0,\xFF,52,61488,D264B8204F0E1828,16777216"),
+            Err(ComError::IvalidGetDataString)
+        );
+    }
+
+    #[test]
+    fn series() {
+        let mut info = DeviceInfo::parse(SAMPLE).unwrap();
+        let models = [
+            ("NX4832T035_011R", NextionSeries::Basic),
+            ("NX8048K070_011C", NextionSeries::Enhanced),
+            ("NX1060P101_011R", NextionSeries::Intelligent),
+            ("NX4832F035_011R", NextionSeries::Discovery),
+            ("NX4832X035_011R", NextionSeries::Unknown),
+            ("", NextionSeries::Unknown),
+        ];
+        for (model, series) in models {
+            info.model = model.into();
+            assert_eq!(info.series(), series);
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use core::fmt::Write;
+
+use embedded_hal::blocking::serial as blocking;
+use heapless::String;
+
+use super::{literal::StrLiteral, ComError, Nextion};
+
+/// Size in bytes of the EEPROM of Enhanced and Intelligent displays.
+pub const EEPROM_SIZE: usize = 1024;
+
+/// Access to the display EEPROM, returned by [`Nextion::eeprom`].
+///
+/// Addresses range from `0` to [`EEPROM_SIZE`], a value or string must fit
+/// entirely, otherwise [`ComError::InvalidDataRange`] is returned.
+pub struct Eeprom<'a, USART> {
+    nextion: &'a mut Nextion<USART>,
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Returns the EEPROM accessor.
+    pub fn eeprom(&mut self) -> Eeprom<'_, USART> {
+        Eeprom { nextion: self }
+    }
+}
+
+impl<'a, USART> Eeprom<'a, USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Writes the 4 bytes of `value` at `addr` with `wepo`.
+    pub fn write_value(&mut self, addr: u16, value: i32) -> Result<(), ComError> {
+        match check_range(addr, 4) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "wepo {},{}", value, addr) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    /// Reads the 4 bytes at `addr` with `repo`, through `sys0` which is
+    /// overwritten.
+    pub fn read_value(&mut self, addr: u16) -> Result<i32, ComError> {
+        match check_range(addr, 4) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<16>::new();
+        match write!(cmd, "repo sys0,{}", addr) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        match self.nextion.send_cmd(cmd.as_bytes()) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.nextion.get_sys_var("sys0")
+    }
+
+    /// Writes `txt` and its terminating null byte at `addr` with `wepo`.
+    pub fn write_str(&mut self, addr: u16, txt: &str) -> Result<(), ComError> {
+        match check_range(addr, txt.len() + 1) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let literal = match StrLiteral::new(txt) {
+            Ok(x) => x,
+            Err(_) => return Err(ComError::InvalidValue),
+        };
+        let mut cmd = String::<265>::new();
+        match write!(cmd, "wepo {},{}", literal, addr) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    /// Writes `data` at `addr` with `wept`, streamed as transparent data.
+    pub fn write_bytes(&mut self, addr: u16, data: &[u8]) -> Result<(), ComError> {
+        match check_range(addr, data.len()) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<16>::new();
+        match write!(cmd, "wept {},{}", addr, data.len()) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_transparent(cmd.as_bytes(), data)
+    }
+
+    /// Reads `buff.len()` bytes at `addr` with `rept`.
+    pub fn read_bytes(&mut self, addr: u16, buff: &mut [u8]) -> Result<(), ComError> {
+        match check_range(addr, buff.len()) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<16>::new();
+        match write!(cmd, "rept {},{}", addr, buff.len()) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        match self.nextion.send_query(cmd.as_bytes()) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        // the bytes are sent as is, without a frame
+        for byte in buff.iter_mut() {
+            match self.nextion.read_raw() {
+                Ok(x) => *byte = x,
+                Err(err) => return Err(err),
+            };
+        }
+        Ok(())
+    }
+}
+
+fn check_range(addr: u16, len: usize) -> Result<(), ComError> {
+    match addr as usize + len > EEPROM_SIZE {
+        true => Err(ComError::InvalidDataRange),
+        false => Ok(()),
+    }
+}
@@ -0,0 +1,347 @@
+use heapless::Vec;
+
+use super::{ComError, IntoU8, NextionCmd, NextionReturnCode};
+
+/// Frame sent by the display, as yielded by [`FrameParser::feed`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Frame<'a> {
+    /// `0x65`, component touch event.
+    Touch {
+        page: u8,
+        component: u8,
+        pressed: bool,
+    },
+    /// `0x66`, current page id.
+    PageId(u8),
+    /// `0x67` touch coordinate, or `0x68` when the display is sleeping.
+    TouchXY {
+        x: u16,
+        y: u16,
+        pressed: bool,
+        asleep: bool,
+    },
+    /// `0x70`, string data without the terminator.
+    String(&'a [u8]),
+    /// `0x71`, little endian signed number.
+    Number(i32),
+    /// `0x86`, display entered sleep mode.
+    Sleep,
+    /// `0x87`, display left sleep mode.
+    Wake,
+    /// `0x00 0x00 0x00`, display powered on.
+    Startup,
+    /// `0x88`, display is ready after power on.
+    Ready,
+    /// `0x89`, display started a microSD upgrade.
+    Upgrade,
+    /// Answer to an instruction.
+    ReturnCode(NextionReturnCode),
+    /// `0xFE`, ready to receive transparent data.
+    TransparentReady,
+    /// `0xFD`, transparent data received.
+    TransparentDone,
+    /// Answer to `connect`, the fields following `comok `.
+    Comok(&'a [u8]),
+}
+
+/// Incremental parser for frames sent by the display.
+///
+/// Bytes are fed one at a time, so the parser can be driven from polling,
+/// an interrupt handler or a DMA buffer. `N` is the maximum payload length
+/// kept for variable length frames.
+#[derive(Clone)]
+pub struct FrameParser<const N: usize> {
+    head: Option<u8>,
+    buffer: Vec<u8, N>,
+    ends: u8,
+    overflow: bool,
+}
+
+impl<const N: usize> Default for FrameParser<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> FrameParser<N> {
+    /// Creates a new [`FrameParser`].
+    pub fn new() -> Self {
+        FrameParser {
+            head: None,
+            buffer: Vec::new(),
+            ends: 0,
+            overflow: false,
+        }
+    }
+
+    /// Drops any partially received frame.
+    pub fn reset(&mut self) {
+        self.head = None;
+        self.buffer.clear();
+        self.ends = 0;
+        self.overflow = false;
+    }
+
+    /// Returns `true` while a frame is partially received.
+    pub fn is_busy(&self) -> bool {
+        self.head.is_some()
+    }
+
+    /// Payload length of frames with a fixed size, `None` for frames that
+    /// end at the first `FF FF FF`.
+    fn payload_len(head: u8) -> Option<usize> {
+        match head {
+            0x65 => Some(3),
+            0x66 => Some(1),
+            0x67 | 0x68 => Some(5),
+            0x71 => Some(4),
+            0x86..=0x89 | 0xFD | 0xFE => Some(0),
+            0x00 | 0x63 | 0x70 => None,
+            _ => NextionReturnCode::from_u8(head).map(|_| 0),
+        }
+    }
+
+    /// Feeds one byte, returns a frame once its terminator is received.
+    ///
+    /// Fixed size payloads are counted rather than scanned, so `0xFF` bytes
+    /// inside a number are not taken as the terminator.
+    pub fn feed(&mut self, word: u8) -> Result<Option<Frame<'_>>, ComError> {
+        let head = match self.head {
+            Some(x) => x,
+            None => {
+                // the payload of the previous frame may still be borrowed
+                // until the next byte arrives
+                self.buffer.clear();
+                self.head = Some(word);
+                return Ok(None);
+            }
+        };
+        let end = NextionCmd::CmdEnd.into_u8();
+
+        match Self::payload_len(head) {
+            Some(len) => {
+                if self.buffer.len() < len {
+                    if self.buffer.push(word).is_err() {
+                        self.overflow = true;
+                    }
+                    return Ok(None);
+                }
+                if end != word {
+                    self.reset();
+                    return Err(ComError::InvalidEventFrame);
+                }
+                self.ends += 1;
+            }
+            None => {
+                if end == word {
+                    self.ends += 1;
+                } else {
+                    for _ in 0..self.ends {
+                        if self.buffer.push(end).is_err() {
+                            self.overflow = true;
+                        }
+                    }
+                    self.ends = 0;
+                    if self.buffer.push(word).is_err() {
+                        self.overflow = true;
+                    }
+                }
+            }
+        }
+
+        if self.ends < 3 {
+            return Ok(None);
+        }
+
+        let overflow = self.overflow;
+        self.head = None;
+        self.ends = 0;
+        self.overflow = false;
+        if overflow {
+            return Err(ComError::IvalidGetDataString);
+        }
+
+        Self::decode(head, &self.buffer)
+    }
+
+    fn decode(head: u8, payload: &[u8]) -> Result<Option<Frame<'_>>, ComError> {
+        let frame = match head {
+            0x65 => {
+                if payload[2] > 1 {
+                    return Err(ComError::InvalidEventFrame);
+                }
+                Frame::Touch {
+                    page: payload[0],
+                    component: payload[1],
+                    pressed: payload[2] == 1,
+                }
+            }
+            0x66 => Frame::PageId(payload[0]),
+            0x67 | 0x68 => {
+                if payload[4] > 1 {
+                    return Err(ComError::InvalidEventFrame);
+                }
+                Frame::TouchXY {
+                    x: (payload[0] as u16) << 8 | payload[1] as u16,
+                    y: (payload[2] as u16) << 8 | payload[3] as u16,
+                    pressed: payload[4] == 1,
+                    asleep: head == 0x68,
+                }
+            }
+            0x70 => Frame::String(payload),
+            0x63 => match payload.strip_prefix(b"omok ") {
+                Some(data) => Frame::Comok(data),
+                None => return Ok(None),
+            },
+            0x71 => Frame::Number(i32::from_le_bytes([
+                payload[0], payload[1], payload[2], payload[3],
+            ])),
+            0x86 => Frame::Sleep,
+            0x87 => Frame::Wake,
+            0x88 => Frame::Ready,
+            0x89 => Frame::Upgrade,
+            0xFD => Frame::TransparentDone,
+            0xFE => Frame::TransparentReady,
+            0x00 => match payload {
+                [] => Frame::ReturnCode(NextionReturnCode::InvalidInstruction),
+                [0x00, 0x00] => Frame::Startup,
+                _ => return Err(ComError::InvalidEventFrame),
+            },
+            _ => match NextionReturnCode::from_u8(head) {
+                Some(code) => Frame::ReturnCode(code),
+                // unknown frame, skipped up to its terminator
+                None => return Ok(None),
+            },
+        };
+        Ok(Some(frame))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feeds `bytes`, none of which may end a frame.
+    fn feed_head<const N: usize>(parser: &mut FrameParser<N>, bytes: &[u8]) {
+        for word in bytes {
+            assert_eq!(parser.feed(*word), Ok(None));
+        }
+    }
+
+    #[test]
+    fn number_with_ff_bytes() {
+        let mut parser = FrameParser::<16>::new();
+        feed_head(&mut parser, &[0x71, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(parser.feed(0xFF), Ok(Some(Frame::Number(-1))));
+
+        feed_head(&mut parser, &[0x71, 0x05, 0xFF, 0xFF, 0x00, 0xFF, 0xFF]);
+        assert_eq!(parser.feed(0xFF), Ok(Some(Frame::Number(0x00FF_FF05))));
+        assert!(!parser.is_busy());
+    }
+
+    #[test]
+    fn string_with_ff_bytes() {
+        let mut parser = FrameParser::<16>::new();
+        feed_head(
+            &mut parser,
+            &[0x70, b'a', 0xFF, b'b', 0xFF, 0xFF, b'c', 0xFF, 0xFF],
+        );
+        assert_eq!(
+            parser.feed(0xFF),
+            Ok(Some(Frame::String(&[b'a', 0xFF, b'b', 0xFF, 0xFF, b'c'])))
+        );
+    }
+
+    #[test]
+    fn empty_string() {
+        let mut parser = FrameParser::<16>::new();
+        feed_head(&mut parser, &[0x70, 0xFF, 0xFF]);
+        assert_eq!(parser.feed(0xFF), Ok(Some(Frame::String(&[]))));
+    }
+
+    #[test]
+    fn invalid_instruction_and_startup() {
+        let mut parser = FrameParser::<16>::new();
+        feed_head(&mut parser, &[0x00, 0xFF, 0xFF]);
+        assert_eq!(
+            parser.feed(0xFF),
+            Ok(Some(Frame::ReturnCode(
+                NextionReturnCode::InvalidInstruction
+            )))
+        );
+
+        feed_head(&mut parser, &[0x00, 0x00, 0x00, 0xFF, 0xFF]);
+        assert_eq!(parser.feed(0xFF), Ok(Some(Frame::Startup)));
+
+        feed_head(&mut parser, &[0x00, 0x00, 0xFF, 0xFF]);
+        assert_eq!(parser.feed(0xFF), Err(ComError::InvalidEventFrame));
+    }
+
+    #[test]
+    fn overflow() {
+        let mut parser = FrameParser::<4>::new();
+        feed_head(
+            &mut parser,
+            &[0x70, b'a', b'b', b'c', b'd', b'e', 0xFF, 0xFF],
+        );
+        assert_eq!(parser.feed(0xFF), Err(ComError::IvalidGetDataString));
+        assert!(!parser.is_busy());
+
+        // the next frame is parsed normally
+        feed_head(&mut parser, &[0x70, b'a', 0xFF, 0xFF]);
+        assert_eq!(parser.feed(0xFF), Ok(Some(Frame::String(b"a"))));
+    }
+
+    #[test]
+    fn missing_terminator() {
+        let mut parser = FrameParser::<16>::new();
+        feed_head(&mut parser, &[0x66, 0x02]);
+        assert_eq!(parser.feed(0x03), Err(ComError::InvalidEventFrame));
+        assert!(!parser.is_busy());
+    }
+
+    #[test]
+    fn unknown_head() {
+        let mut parser = FrameParser::<16>::new();
+        feed_head(&mut parser, &[0x50, 0x01, 0xFF, 0x02, 0xFF, 0xFF]);
+        assert_eq!(parser.feed(0xFF), Ok(None));
+        assert!(!parser.is_busy());
+
+        feed_head(&mut parser, &[0x66, 0x02, 0xFF, 0xFF]);
+        assert_eq!(parser.feed(0xFF), Ok(Some(Frame::PageId(2))));
+    }
+
+    #[test]
+    fn events() {
+        let mut parser = FrameParser::<16>::new();
+        feed_head(&mut parser, &[0x65, 0x01, 0x02, 0x01, 0xFF, 0xFF]);
+        assert_eq!(
+            parser.feed(0xFF),
+            Ok(Some(Frame::Touch {
+                page: 1,
+                component: 2,
+                pressed: true
+            }))
+        );
+
+        feed_head(
+            &mut parser,
+            &[0x68, 0x01, 0x02, 0x00, 0xFF, 0x00, 0xFF, 0xFF],
+        );
+        assert_eq!(
+            parser.feed(0xFF),
+            Ok(Some(Frame::TouchXY {
+                x: 0x0102,
+                y: 0x00FF,
+                pressed: false,
+                asleep: true
+            }))
+        );
+
+        feed_head(&mut parser, &[0x1A, 0xFF, 0xFF]);
+        assert_eq!(
+            parser.feed(0xFF),
+            Ok(Some(Frame::ReturnCode(NextionReturnCode::InvalidVariable)))
+        );
+    }
+}
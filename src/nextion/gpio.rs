@@ -0,0 +1,276 @@
+use core::fmt::Write;
+
+use embedded_hal::blocking::serial as blocking;
+use embedded_hal::digital::v2::{InputPin, OutputPin, StatefulOutputPin};
+use heapless::String;
+
+use super::{get_device, ComError, IntoU8, Nextion};
+use crate::components::BaseInfo;
+
+/// Pin mode of the I/O extension board set with `cfgpio`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum GpioMode {
+    /// Input with pull-up.
+    InputPullUp = 0,
+    /// Input bound to a component, see [`Gpio::bind`].
+    InputBinding = 1,
+    /// Push-pull output.
+    PushPull = 2,
+    /// PWM output, pins 4 to 7 only.
+    Pwm = 3,
+    /// Open-drain output.
+    OpenDrain = 4,
+}
+
+impl IntoU8 for GpioMode {
+    fn into_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+impl GpioMode {
+    pub fn from_u8(data: u8) -> Result<Self, ComError> {
+        match data {
+            0 => Ok(Self::InputPullUp),
+            1 => Ok(Self::InputBinding),
+            2 => Ok(Self::PushPull),
+            3 => Ok(Self::Pwm),
+            4 => Ok(Self::OpenDrain),
+            _ => Err(ComError::InvalidValue),
+        }
+    }
+}
+
+/// Access to the I/O extension board, returned by [`Nextion::gpio`].
+///
+/// Pins range from 0 to 7, PWM is available on pins 4 to 7.
+pub struct Gpio<'a, USART> {
+    nextion: &'a mut Nextion<USART>,
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Returns the I/O extension board accessor.
+    pub fn gpio(&mut self) -> Gpio<'_, USART> {
+        Gpio { nextion: self }
+    }
+
+    fn cfgpio(&mut self, pin: u8, mode: GpioMode, component: &str) -> Result<(), ComError> {
+        match check_pin(pin) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        if mode == GpioMode::Pwm && pin < 4 {
+            return Err(ComError::InvalidDataRange);
+        }
+        let mut cmd = String::<40>::new();
+        match write!(cmd, "cfgpio {},{},{}", pin, mode.into_u8(), component) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn write_pio(&mut self, pin: u8, high: bool) -> Result<(), ComError> {
+        match check_pin(pin) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<8>::new();
+        match write!(cmd, "pio{}={}", pin, high as u8) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    fn read_pio(&mut self, pin: u8) -> Result<bool, ComError> {
+        match check_pin(pin) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut name = String::<4>::new();
+        match write!(name, "pio{}", pin) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        match self.get_sys_var::<u8>(&name) {
+            Ok(x) => Ok(x != 0),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<'a, USART> Gpio<'a, USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Configures `pin` with `cfgpio`.
+    ///
+    /// [`GpioMode::InputBinding`] needs a component, use [`Gpio::bind`].
+    pub fn configure(&mut self, pin: u8, mode: GpioMode) -> Result<(), ComError> {
+        if mode == GpioMode::InputBinding {
+            return Err(ComError::InvalidValue);
+        }
+        self.nextion.cfgpio(pin, mode, "0")
+    }
+
+    /// Configures `pin` as input bound to `component`, a falling edge
+    /// sends its press event and a rising edge its release event.
+    pub fn bind(&mut self, pin: u8, component: &dyn BaseInfo) -> Result<(), ComError> {
+        self.nextion
+            .cfgpio(pin, GpioMode::InputBinding, component.get_component_name())
+    }
+
+    /// Reads `pioN`.
+    pub fn read(&mut self, pin: u8) -> Result<bool, ComError> {
+        self.nextion.read_pio(pin)
+    }
+
+    /// Writes `pioN`.
+    pub fn write(&mut self, pin: u8, high: bool) -> Result<(), ComError> {
+        self.nextion.write_pio(pin, high)
+    }
+
+    /// Sets `pwmN`, the duty cycle from 0 to 100 of a PWM pin.
+    pub fn set_duty(&mut self, pin: u8, duty: u8) -> Result<(), ComError> {
+        if !(4..=7).contains(&pin) || duty > 100 {
+            return Err(ComError::InvalidDataRange);
+        }
+        let mut cmd = String::<10>::new();
+        match write!(cmd, "pwm{}={}", pin, duty) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    pub fn get_duty(&mut self, pin: u8) -> Result<u8, ComError> {
+        if !(4..=7).contains(&pin) {
+            return Err(ComError::InvalidDataRange);
+        }
+        let mut name = String::<4>::new();
+        match write!(name, "pwm{}", pin) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.get_sys_var(&name)
+    }
+
+    /// Sets `pwmf`, the frequency in Hz shared by all PWM pins.
+    pub fn set_pwm_frequency(&mut self, freq: u16) -> Result<(), ComError> {
+        if freq == 0 {
+            return Err(ComError::InvalidDataRange);
+        }
+        self.nextion.set_sys_var("pwmf", freq)
+    }
+
+    pub fn get_pwm_frequency(&mut self) -> Result<u16, ComError> {
+        self.nextion.get_sys_var("pwmf")
+    }
+
+    /// Configures `pin` as input with pull-up and returns it as a
+    /// [`GpioInput`].
+    pub fn into_input(&mut self, pin: u8) -> Result<GpioInput<USART>, ComError> {
+        match self.configure(pin, GpioMode::InputPullUp) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        Ok(GpioInput {
+            device: self.nextion,
+            pin,
+        })
+    }
+
+    /// Configures `pin` as output with `mode` and returns it as a
+    /// [`GpioOutput`].
+    ///
+    /// `mode` must be [`GpioMode::PushPull`] or [`GpioMode::OpenDrain`],
+    /// otherwise [`ComError::InvalidValue`] is returned.
+    pub fn into_output(&mut self, pin: u8, mode: GpioMode) -> Result<GpioOutput<USART>, ComError> {
+        match mode {
+            GpioMode::PushPull | GpioMode::OpenDrain => {}
+            _ => return Err(ComError::InvalidValue),
+        };
+        match self.configure(pin, mode) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        Ok(GpioOutput {
+            device: self.nextion,
+            pin,
+        })
+    }
+}
+
+/// Input pin of the I/O extension board, usable as an embedded-hal
+/// [`InputPin`].
+pub struct GpioInput<USART> {
+    device: *mut Nextion<USART>,
+    pin: u8,
+}
+
+impl<USART> InputPin for GpioInput<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    type Error = ComError;
+
+    fn is_high(&self) -> Result<bool, Self::Error> {
+        get_device(self.device).read_pio(self.pin)
+    }
+
+    fn is_low(&self) -> Result<bool, Self::Error> {
+        match self.is_high() {
+            Ok(x) => Ok(!x),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// Output pin of the I/O extension board, usable as an embedded-hal
+/// [`OutputPin`].
+pub struct GpioOutput<USART> {
+    device: *mut Nextion<USART>,
+    pin: u8,
+}
+
+impl<USART> OutputPin for GpioOutput<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    type Error = ComError;
+
+    fn set_low(&mut self) -> Result<(), Self::Error> {
+        get_device(self.device).write_pio(self.pin, false)
+    }
+
+    fn set_high(&mut self) -> Result<(), Self::Error> {
+        get_device(self.device).write_pio(self.pin, true)
+    }
+}
+
+impl<USART> StatefulOutputPin for GpioOutput<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    fn is_set_high(&self) -> Result<bool, Self::Error> {
+        get_device(self.device).read_pio(self.pin)
+    }
+
+    fn is_set_low(&self) -> Result<bool, Self::Error> {
+        match self.is_set_high() {
+            Ok(x) => Ok(!x),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+fn check_pin(pin: u8) -> Result<(), ComError> {
+    match pin > 7 {
+        true => Err(ComError::InvalidDataRange),
+        false => Ok(()),
+    }
+}
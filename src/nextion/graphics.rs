@@ -0,0 +1,236 @@
+use embedded_graphics::{
+    draw_target::DrawTarget,
+    geometry::{Dimensions, OriginDimensions, Point, Size},
+    pixelcolor::{IntoStorage, Rgb565},
+    primitives::{Line, PrimitiveStyle, Rectangle, Styled},
+    text::{
+        renderer::{CharacterStyle, TextMetrics},
+        Alignment, Baseline, Text,
+    },
+    Drawable, Pixel,
+};
+use embedded_hal::blocking::serial as blocking;
+
+use super::{
+    canvas::{Canvas, TextBackground},
+    ComError,
+};
+use crate::components::objects::{TextHorizontalAlignment, TextVerticalAlignment};
+
+impl<'a, USART> OriginDimensions for Canvas<'a, USART> {
+    fn size(&self) -> Size {
+        Size::new(self.width as u32, self.height as u32)
+    }
+}
+
+/// Pixels are sent as `fill` commands, consecutive pixels of the same color
+/// on a row are coalesced into one command.
+impl<'a, USART> DrawTarget for Canvas<'a, USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    type Color = Rgb565;
+    type Error = ComError;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<Self::Color>>,
+    {
+        let area = self.bounding_box();
+        // x, y, length and color of the pending run
+        let mut run: Option<(u16, u16, u16, u16)> = None;
+        for Pixel(point, color) in pixels {
+            if !area.contains(point) {
+                continue;
+            }
+            let (x, y, color) = (point.x as u16, point.y as u16, color.into_storage());
+            run = match run {
+                Some((rx, ry, len, rc)) if ry == y && rc == color && rx + len == x => {
+                    Some((rx, ry, len + 1, rc))
+                }
+                Some((rx, ry, len, rc)) => {
+                    match self.fill(rx, ry, len, 1, rc) {
+                        Ok(_) => {}
+                        Err(err) => return Err(err),
+                    };
+                    Some((x, y, 1, color))
+                }
+                None => Some((x, y, 1, color)),
+            };
+        }
+        match run {
+            Some((rx, ry, len, rc)) => self.fill(rx, ry, len, 1, rc),
+            None => Ok(()),
+        }
+    }
+
+    fn fill_solid(&mut self, area: &Rectangle, color: Self::Color) -> Result<(), Self::Error> {
+        let area = area.intersection(&self.bounding_box());
+        if area.is_zero_sized() {
+            return Ok(());
+        }
+        self.fill(
+            area.top_left.x as u16,
+            area.top_left.y as u16,
+            area.size.width as u16,
+            area.size.height as u16,
+            color.into_storage(),
+        )
+    }
+
+    fn clear(&mut self, color: Self::Color) -> Result<(), Self::Error> {
+        self.cls(color.into_storage())
+    }
+}
+
+impl<'a, USART> Canvas<'a, USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Draws a styled line with a single `line` command when it is one
+    /// pixel wide and fits on the screen, pixel by pixel otherwise.
+    pub fn draw_line(
+        &mut self,
+        line: &Styled<Line, PrimitiveStyle<Rgb565>>,
+    ) -> Result<(), ComError> {
+        let Line { start, end } = line.primitive;
+        let area = self.bounding_box();
+        match line.style.stroke_color {
+            Some(color)
+                if line.style.stroke_width == 1 && area.contains(start) && area.contains(end) =>
+            {
+                self.line(
+                    start.x as u16,
+                    start.y as u16,
+                    end.x as u16,
+                    end.y as u16,
+                    color.into_storage(),
+                )
+            }
+            _ => line.draw(self),
+        }
+    }
+
+    /// Prints `text` with one `xstr` command per line, following its
+    /// alignment, baseline and line height like `Text::draw` does for other
+    /// styles.
+    ///
+    /// Returns the position after the last character.
+    pub fn draw_text(&mut self, text: &Text<'_, XstrStyle>) -> Result<Point, ComError> {
+        let style = &text.character_style;
+        let baseline = text.text_style.baseline;
+        let line_height = text.text_style.line_height.to_absolute(style.line_height) as i32;
+        let background = match style.background_color {
+            Some(x) => TextBackground::Color(x.into_storage()),
+            None => TextBackground::None,
+        };
+
+        let mut position = text.position;
+        let mut next_position = position;
+        for line in text.text.split('\n') {
+            // `\r\n` line endings
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            let advance = style
+                .measure_string(line, Point::zero(), baseline)
+                .next_position;
+            let start = match text.text_style.alignment {
+                Alignment::Left => position,
+                Alignment::Center => position - (advance - Point::new(1, 0)) / 2,
+                Alignment::Right => position - (advance - Point::new(1, 0)),
+            };
+            let metrics = style.measure_string(line, start, baseline);
+            next_position = metrics.next_position;
+            position.y += line_height;
+
+            let area = metrics.bounding_box;
+            let color = match style.text_color {
+                Some(x) => x,
+                None => continue,
+            };
+            if area.is_zero_sized() {
+                continue;
+            }
+            if area.top_left.x < 0 || area.top_left.y < 0 {
+                return Err(ComError::InvalidDataRange);
+            }
+            match self.xstr(
+                area.top_left.x as u16,
+                area.top_left.y as u16,
+                area.size.width as u16,
+                area.size.height as u16,
+                style.font,
+                color.into_storage(),
+                background,
+                TextHorizontalAlignment::Left,
+                TextVerticalAlignment::Top,
+                line,
+            ) {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+        }
+        Ok(next_position)
+    }
+}
+
+/// Character style of text printed with `xstr` in a device font, drawn by
+/// [`Canvas::draw_text`].
+///
+/// `char_width` and `line_height` give the size in pixels of the font
+/// characters, used to measure the text. The glyphs are only known to the
+/// display and a generic draw target can not send `xstr`, so the style is not
+/// a `TextRenderer`: `Text::draw` is rejected at compile time instead of
+/// drawing nothing.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct XstrStyle {
+    font: u8,
+    char_width: u32,
+    line_height: u32,
+    text_color: Option<Rgb565>,
+    background_color: Option<Rgb565>,
+}
+
+impl XstrStyle {
+    /// Creates a new [`XstrStyle`] for the device `font` without background.
+    pub fn new(font: u8, char_width: u32, line_height: u32, color: Rgb565) -> Self {
+        XstrStyle {
+            font,
+            char_width,
+            line_height,
+            text_color: Some(color),
+            background_color: None,
+        }
+    }
+
+    fn measure_string(&self, text: &str, position: Point, baseline: Baseline) -> TextMetrics {
+        let width = self.char_width * text.chars().count() as u32;
+        TextMetrics {
+            bounding_box: Rectangle::new(
+                self.top_left(position, baseline),
+                Size::new(width, self.line_height),
+            ),
+            next_position: position + Point::new(width as i32, 0),
+        }
+    }
+
+    fn top_left(&self, position: Point, baseline: Baseline) -> Point {
+        let height = self.line_height as i32;
+        match baseline {
+            Baseline::Top => position,
+            Baseline::Middle => position - Point::new(0, (height - 1) / 2),
+            Baseline::Bottom | Baseline::Alphabetic => position - Point::new(0, height - 1),
+        }
+    }
+}
+
+impl CharacterStyle for XstrStyle {
+    type Color = Rgb565;
+
+    fn set_text_color(&mut self, text_color: Option<Self::Color>) {
+        self.text_color = text_color;
+    }
+
+    fn set_background_color(&mut self, background_color: Option<Self::Color>) {
+        self.background_color = background_color;
+    }
+}
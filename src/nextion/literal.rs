@@ -0,0 +1,86 @@
+use core::fmt::{Display, Formatter, Write};
+
+use super::ComError;
+
+/// Text written as a quoted Nextion string literal.
+///
+/// `"` and `\` are escaped, `\r`, `\n` and `\r\n` become the `\r` line
+/// break sequence. Other control characters could end the command early and
+/// are rejected by [`StrLiteral::new`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct StrLiteral<'a>(&'a str);
+
+impl<'a> StrLiteral<'a> {
+    /// Creates a new [`StrLiteral`], [`ComError::InvalidValue`] is returned
+    /// when `txt` contains control characters other than `\r` and `\n`.
+    pub fn new(txt: &'a str) -> Result<Self, ComError> {
+        let valid = txt
+            .chars()
+            .all(|c| c == '\r' || c == '\n' || !c.is_control());
+        match valid {
+            true => Ok(StrLiteral(txt)),
+            false => Err(ComError::InvalidValue),
+        }
+    }
+}
+
+impl Display for StrLiteral<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.write_char('"')?;
+        let mut chars = self.0.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\r' => {
+                    // `\r\n` is a single line break
+                    chars.next_if_eq(&'\n');
+                    f.write_str("\\r")?
+                }
+                '\n' => f.write_str("\\r")?,
+                _ => f.write_char(c)?,
+            };
+        }
+        f.write_char('"')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use heapless::String;
+
+    fn quote(txt: &str) -> String<64> {
+        let mut out = String::new();
+        write!(out, "{}", StrLiteral::new(txt).unwrap()).unwrap();
+        out
+    }
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(quote(""), "\"\"");
+        assert_eq!(quote("hello world"), "\"hello world\"");
+        assert_eq!(quote("é°"), "\"é°\"");
+    }
+
+    #[test]
+    fn escapes_quotes_and_backslashes() {
+        assert_eq!(quote("a\"b"), "\"a\\\"b\"");
+        assert_eq!(quote("a\\b"), "\"a\\\\b\"");
+        assert_eq!(quote("\\\""), "\"\\\\\\\"\"");
+    }
+
+    #[test]
+    fn line_breaks() {
+        assert_eq!(quote("a\rb\nc\r\nd"), "\"a\\rb\\rc\\rd\"");
+        assert_eq!(quote("a\n\rb"), "\"a\\r\\rb\"");
+        assert_eq!(quote("a\r\n\r\nb"), "\"a\\r\\rb\"");
+    }
+
+    #[test]
+    fn rejects_control_characters() {
+        assert_eq!(StrLiteral::new("a\0b"), Err(ComError::InvalidValue));
+        assert_eq!(StrLiteral::new("a\tb"), Err(ComError::InvalidValue));
+        assert_eq!(StrLiteral::new("\u{7f}"), Err(ComError::InvalidValue));
+    }
+}
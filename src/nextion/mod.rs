@@ -1,9 +1,39 @@
 use core::cell::RefCell;
+use core::fmt::{Display, Write};
 
 use embedded_hal::blocking::serial as blocking;
+use heapless::{Deque, String};
 use num_traits::{NumCast, PrimInt};
 
-use crate::components::ObjInfo;
+use crate::components::{objects::TouchComponent, BaseInfo, ObjInfo};
+
+use self::frame::{Frame, FrameParser};
+use self::touch::TouchPoint;
+
+pub mod audio;
+pub mod baud;
+pub mod canvas;
+pub mod device;
+pub mod eeprom;
+pub mod frame;
+pub mod gpio;
+#[cfg(feature = "graphics")]
+pub mod graphics;
+pub mod literal;
+pub mod page;
+pub mod power;
+pub mod reparse;
+pub mod rtc;
+pub mod rx;
+pub mod system;
+pub mod touch;
+pub mod upload;
+
+/// Maximum payload length of a frame received from the display.
+pub const MAX_FRAME_LEN: usize = 256;
+
+/// Maximum number of events kept while waiting for an answer.
+pub const MAX_PENDING_EVENTS: usize = 8;
 // #[repr(u8)]
 #[derive(PartialEq)]
 pub enum NextionCmd {
@@ -27,6 +57,95 @@ pub enum NextionCmd {
     CmdInvalidOperation = 0x1B,
 }
 
+/// Return code sent by the display in answer to an instruction.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NextionReturnCode {
+    InvalidInstruction = 0x00,
+    Success = 0x01,
+    InvalidComponentId = 0x02,
+    InvalidPageId = 0x03,
+    InvalidPictureId = 0x04,
+    InvalidFontId = 0x05,
+    InvalidFileOperation = 0x06,
+    InvalidCrc = 0x09,
+    InvalidBaud = 0x11,
+    InvalidWaveform = 0x12,
+    InvalidVariable = 0x1A,
+    InvalidOperation = 0x1B,
+    AssignmentFailed = 0x1C,
+    EepromFailed = 0x1D,
+    InvalidParameterCount = 0x1E,
+    IoFailed = 0x1F,
+    InvalidEscapeChar = 0x20,
+    NameTooLong = 0x23,
+    BufferOverflow = 0x24,
+}
+
+impl IntoU8 for NextionReturnCode {
+    fn into_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+impl NextionReturnCode {
+    pub fn from_u8(data: u8) -> Option<Self> {
+        match data {
+            0x00 => Some(Self::InvalidInstruction),
+            0x01 => Some(Self::Success),
+            0x02 => Some(Self::InvalidComponentId),
+            0x03 => Some(Self::InvalidPageId),
+            0x04 => Some(Self::InvalidPictureId),
+            0x05 => Some(Self::InvalidFontId),
+            0x06 => Some(Self::InvalidFileOperation),
+            0x09 => Some(Self::InvalidCrc),
+            0x11 => Some(Self::InvalidBaud),
+            0x12 => Some(Self::InvalidWaveform),
+            0x1A => Some(Self::InvalidVariable),
+            0x1B => Some(Self::InvalidOperation),
+            0x1C => Some(Self::AssignmentFailed),
+            0x1D => Some(Self::EepromFailed),
+            0x1E => Some(Self::InvalidParameterCount),
+            0x1F => Some(Self::IoFailed),
+            0x20 => Some(Self::InvalidEscapeChar),
+            0x23 => Some(Self::NameTooLong),
+            0x24 => Some(Self::BufferOverflow),
+            _ => None,
+        }
+    }
+}
+
+/// Acknowledgement level set on the display with `bkcmd`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum AckMode {
+    /// No return frame at all.
+    Off = 0,
+    /// Only `0x01` on success.
+    OnSuccess = 1,
+    /// Only error frames, display default.
+    OnFailure = 2,
+    /// Success and error frames.
+    Always = 3,
+}
+
+impl IntoU8 for AckMode {
+    fn into_u8(self) -> u8 {
+        self as u8
+    }
+}
+
+impl AckMode {
+    pub fn from_u8(data: u8) -> Result<Self, ComError> {
+        match data {
+            0 => Ok(Self::Off),
+            1 => Ok(Self::OnSuccess),
+            2 => Ok(Self::OnFailure),
+            3 => Ok(Self::Always),
+            _ => Err(ComError::InvalidValue),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum ComError {
     FailedCreateCommand,
     PeripheralNotFound,
@@ -38,6 +157,135 @@ pub enum ComError {
     IvalidGetDataNumber,
     InvalidDataRange,
     InvalidValue,
+    InvalidEventFrame,
+    /// The component is not on the page currently loaded.
+    PageNotLoaded,
+    /// Instructions cannot be sent while Protocol Reparse mode is active.
+    ReparseModeActive,
+    /// The receive queue was full and a byte was dropped.
+    RxOverrun,
+    /// The display did not answer before the [`ReadTimeout`].
+    Timeout,
+    /// The display answered with an error frame.
+    Device(NextionReturnCode),
+}
+
+/// Event sent by the display without being requested.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NextionEvent {
+    /// Component `component` on page `page` was pressed or released.
+    Touch {
+        page: u8,
+        component: u8,
+        pressed: bool,
+    },
+    /// The display was powered on or reset.
+    Startup,
+    /// The display finished its initialization and accepts instructions.
+    Ready,
+    /// The display entered sleep mode.
+    Sleep,
+    /// The display left sleep mode.
+    Wake,
+    /// The panel was touched with `sendxy=1`.
+    TouchXY(TouchPoint),
+    /// The panel was touched while the display was sleeping.
+    SleepTouch(TouchPoint),
+    /// Page id sent by `sendme`, usually when a page is loaded.
+    Page(u8),
+}
+
+impl NextionEvent {
+    /// Returns the event carried by `frame`, if it is not an answer.
+    ///
+    /// `0x66` page frames are events unless requested by
+    /// [`Nextion::current_page`].
+    pub fn from_frame(frame: &Frame) -> Option<Self> {
+        match *frame {
+            Frame::Touch {
+                page,
+                component,
+                pressed,
+            } => Some(NextionEvent::Touch {
+                page,
+                component,
+                pressed,
+            }),
+            Frame::Startup => Some(NextionEvent::Startup),
+            Frame::Ready => Some(NextionEvent::Ready),
+            Frame::Sleep => Some(NextionEvent::Sleep),
+            Frame::Wake => Some(NextionEvent::Wake),
+            Frame::TouchXY {
+                x,
+                y,
+                pressed,
+                asleep,
+            } => {
+                let point = TouchPoint { x, y, pressed };
+                match asleep {
+                    true => Some(NextionEvent::SleepTouch(point)),
+                    false => Some(NextionEvent::TouchXY(point)),
+                }
+            }
+            Frame::PageId(page) => Some(NextionEvent::Page(page)),
+            _ => None,
+        }
+    }
+}
+
+/// Bound on how long an answer from the display is awaited.
+#[derive(Clone, Copy)]
+pub enum ReadTimeout {
+    /// Wait forever.
+    Infinite,
+    /// Give up after this many reads returned `WouldBlock`.
+    Polls(u32),
+    /// Give up once `now` advanced by `ticks`. `now` is a free running,
+    /// wrapping tick counter, like a SysTick or DWT cycle count.
+    Ticks { now: fn() -> u32, ticks: u32 },
+}
+
+impl ReadTimeout {
+    fn start(self) -> Deadline {
+        let start = match self {
+            ReadTimeout::Ticks { now, .. } => now(),
+            _ => 0,
+        };
+        Deadline {
+            timeout: self,
+            start,
+            polls: 0,
+        }
+    }
+}
+
+struct Deadline {
+    timeout: ReadTimeout,
+    start: u32,
+    polls: u32,
+}
+
+impl Deadline {
+    /// Records one `WouldBlock` read, returns `true` once the timeout is over.
+    fn expired(&mut self) -> bool {
+        match self.timeout {
+            ReadTimeout::Infinite => false,
+            ReadTimeout::Polls(max) => {
+                self.polls = self.polls.saturating_add(1);
+                self.polls > max
+            }
+            ReadTimeout::Ticks { now, ticks } => now().wrapping_sub(self.start) > ticks,
+        }
+    }
+}
+
+/// How long to wait for the next byte of a frame.
+#[derive(Clone, Copy, PartialEq)]
+enum Wait {
+    /// Return as soon as no byte is available.
+    Poll,
+    /// Retry until the [`ReadTimeout`] of the device is over.
+    Timeout,
 }
 
 pub trait IntoU8 {
@@ -70,66 +318,53 @@ impl IntoU8 for NextionCmd {
     }
 }
 
-pub trait NextionCom<USART>: ObjInfo<USART>
+pub trait NextionCom<USART>: ObjInfo<USART> + BaseInfo
 where
     USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
 {
+    /// Returns [`ComError::PageNotLoaded`] when the page loaded on the
+    /// display is known and is not the page of this component.
+    fn check_page(&mut self) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        let pid = self.get_page_id();
+        match self.get_device().cached_page() {
+            Some(page) if page != pid => Err(ComError::PageNotLoaded),
+            _ => Ok(()),
+        }
+    }
+
     fn send_cmd(&mut self, cmd: &[u8]) -> Result<(), ComError>
     where
         Self: Sized,
     {
-        let device = self.get_device();
-        match device.get_peripheral().borrow_mut().bwrite_all(cmd) {
+        match self.check_page() {
             Ok(_) => {}
-            Err(_) => return Err(ComError::FailedWrite),
+            Err(err) => return Err(err),
         };
-        let cmd: [u8; 3] = [0xFF, 0xFF, 0xFF];
-        match device.get_peripheral().borrow_mut().bwrite_all(&cmd) {
+        self.get_device().send_cmd(cmd)
+    }
+
+    fn send_query(&mut self, cmd: &[u8]) -> Result<(), ComError>
+    where
+        Self: Sized,
+    {
+        match self.check_page() {
             Ok(_) => {}
-            Err(_) => return Err(ComError::FailedWrite),
-        };
-        match device.get_peripheral().borrow_mut().bflush() {
-            Ok(_) => (),
-            Err(_) => return Err(ComError::FailedSendCmd),
+            Err(err) => return Err(err),
         };
-        Ok(())
+        self.get_device().send_query(cmd)
     }
 
     fn get_str(&mut self, buff: &mut [u8]) -> Result<u8, ComError>
     where
         Self: Sized,
     {
-        let device = self.get_device();
-        //Get string head
-        let mut len = match device.get_peripheral().borrow_mut().read() {
-            Ok(word) => {
-                if NextionCmd::CmdStringHead.into_u8() == word {
-                    0u8
-                } else {
-                    return Err(ComError::IvalidGetDataString);
-                }
-            }
-            Err(_) => return Err(ComError::FailedRead),
-        };
-        let mut end = 0u8;
-        for buf in buff {
-            *buf = match device.get_peripheral().borrow_mut().read() {
-                Ok(word) => {
-                    if NextionCmd::CmdEnd.into_u8() == word {
-                        end += 1;
-                        if end >= 3 {
-                            break;
-                        }
-                        continue;
-                    }
-                    len += 1;
-                    word
-                }
-                Err(_) => return Err(ComError::FailedRead),
-            }
+        match self.get_device().get_str(buff) {
+            Ok(len) => Ok(len as u8),
+            Err(err) => Err(err),
         }
-
-        Ok(len)
     }
 
     fn get_number<T>(&mut self, buff: &mut T) -> Result<(), ComError>
@@ -137,47 +372,22 @@ where
         Self: Sized,
         T: PrimInt,
     {
-        let device = self.get_device();
-        //Get number head
-        match device.get_peripheral().borrow_mut().read() {
-            Ok(word) => {
-                if NextionCmd::CmdNumberHead.into_u8() != word {
-                    return Err(ComError::IvalidGetDataNumber);
-                }
-            }
-            Err(_) => return Err(ComError::FailedRead),
-        };
-
-        let mut buffer = [0u8; 7];
-        for buf in &mut buffer {
-            *buf = match device.get_peripheral().borrow_mut().read() {
-                Ok(val) => val,
-                Err(_) => return Err(ComError::FailedRead),
-            }
-        }
-
-        if (NextionCmd::CmdEnd.into_u8() == buffer[4])
-            && (NextionCmd::CmdEnd.into_u8() == buffer[5])
-            && (NextionCmd::CmdEnd.into_u8() == buffer[6])
-        {
-            let val = (buffer[3] as i32) << 24
-                | (buffer[2] as i32) << 16
-                | (buffer[1] as i32) << 8
-                | (buffer[3] as i32);
-            *buff = match NumCast::from(val) {
-                Some(x) => x,
-                None => return Err(ComError::IvalidGetDataNumber),
-            };
-        } else {
-            return Err(ComError::IvalidGetDataNumber);
-        }
-        Ok(())
+        self.get_device().get_number(buff)
     }
 }
 
 #[derive(Clone)]
 pub struct Nextion<USART> {
     usart: RefCell<USART>,
+    parser: FrameParser<MAX_FRAME_LEN>,
+    events: Deque<NextionEvent, MAX_PENDING_EVENTS>,
+    ack: AckMode,
+    timeout: ReadTimeout,
+    asleep: bool,
+    page: Option<u8>,
+    reparse: bool,
+    /// An answer may still arrive after its wait timed out.
+    late: bool,
 }
 
 impl<USART> Nextion<USART>
@@ -185,25 +395,279 @@ where
     USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
 {
     /// Creates a new [`Nextion<X>`].
+    ///
+    /// The display is left at its default `bkcmd` level and commands are not
+    /// acknowledged.
     pub fn new(peripheral: USART) -> Self {
         Nextion {
             usart: RefCell::new(peripheral),
+            parser: FrameParser::new(),
+            events: Deque::new(),
+            ack: AckMode::OnFailure,
+            timeout: ReadTimeout::Infinite,
+            asleep: false,
+            page: None,
+            reparse: false,
+            late: false,
         }
     }
 
+    /// Returns the timeout used while waiting for an answer.
+    pub fn get_read_timeout(&self) -> ReadTimeout {
+        self.timeout
+    }
+
+    /// Sets the timeout used while waiting for an answer, after which
+    /// [`ComError::Timeout`] is returned. Defaults to [`ReadTimeout::Infinite`].
+    ///
+    /// [`ComError::InvalidValue`] is returned for [`ReadTimeout::Infinite`]
+    /// while the [`AckMode`] waits for every command.
+    pub fn set_read_timeout(&mut self, timeout: ReadTimeout) -> Result<(), ComError> {
+        match check_ack_timeout(self.ack, timeout) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.timeout = timeout;
+        Ok(())
+    }
+
+    /// Creates a new [`Nextion<X>`] with the read `timeout` and sets the
+    /// display `bkcmd` level to `ack`.
+    pub fn new_with_ack(
+        peripheral: USART,
+        ack: AckMode,
+        timeout: ReadTimeout,
+    ) -> Result<Self, ComError> {
+        let mut nextion = Self::new(peripheral);
+        nextion.timeout = timeout;
+        match nextion.set_ack_mode(ack) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        Ok(nextion)
+    }
+
+    /// Returns the acknowledgement level used by [`Nextion::send_cmd`].
+    pub fn get_ack_mode(&self) -> AckMode {
+        self.ack
+    }
+
+    /// Sets `bkcmd` on the display. In [`AckMode::OnSuccess`] and
+    /// [`AckMode::Always`] every later [`Nextion::send_cmd`] waits for the
+    /// return frame of its command. As [`AckMode::OnSuccess`] sends nothing
+    /// on failure, a failed command ends with [`ComError::Timeout`].
+    ///
+    /// These two modes need a finite [`ReadTimeout`], otherwise
+    /// [`ComError::InvalidValue`] is returned.
+    pub fn set_ack_mode(&mut self, ack: AckMode) -> Result<(), ComError> {
+        match check_ack_timeout(ack, self.timeout) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<8>::new();
+        match write!(cmd, "bkcmd={}", ack.into_u8()) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        match self.send_query(cmd.as_bytes()) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.ack = ack;
+        self.check_ack()
+    }
+
     /// Returns a reference to the get peripheral of this [`Nextion<X>`].
     pub fn get_peripheral(&mut self) -> &RefCell<USART> {
         {
             &self.usart
         }
     }
+
+    fn read(&mut self) -> nb::Result<u8, ComError> {
+        match self.usart.borrow_mut().read() {
+            Ok(word) => Ok(word),
+            Err(nb::Error::WouldBlock) => Err(nb::Error::WouldBlock),
+            Err(_) => Err(nb::Error::Other(ComError::FailedRead)),
+        }
+    }
+
+    /// Drops every byte received so far and any partial frame.
+    pub(crate) fn clear_input(&mut self) -> Result<(), ComError> {
+        loop {
+            match self.read() {
+                Ok(_) => {}
+                Err(nb::Error::WouldBlock) => break,
+                Err(nb::Error::Other(err)) => return Err(err),
+            };
+        }
+        self.parser.reset();
+        self.late = false;
+        Ok(())
+    }
+
+    /// Reads one byte outside of any frame, for the transfers answered
+    /// with raw bytes, bounded by the [`ReadTimeout`].
+    pub(crate) fn read_raw(&mut self) -> Result<u8, ComError> {
+        let mut deadline = self.timeout.start();
+        loop {
+            match self.read() {
+                Ok(word) => return Ok(word),
+                Err(nb::Error::WouldBlock) => {
+                    if deadline.expired() {
+                        self.late = true;
+                        return Err(ComError::Timeout);
+                    }
+                }
+                Err(nb::Error::Other(err)) => return Err(err),
+            };
+        }
+    }
+
+    /// Reads bytes until a frame that is not an event is received and hands
+    /// it to `f`. Events received meanwhile are queued for
+    /// [`Nextion::poll_events`].
+    ///
+    /// With [`Wait::Poll`], returns `Ok(None)` when no byte is available or
+    /// an event was queued.
+    fn next_frame<R, F>(&mut self, wait: Wait, f: F) -> Result<Option<R>, ComError>
+    where
+        F: FnMut(Frame) -> Result<R, ComError>,
+    {
+        self.read_frame(wait, false, f)
+    }
+
+    /// Same as [`Nextion::next_frame`], a `0x66` frame is handed to `f`
+    /// instead of being queued when `page_reply` is set.
+    fn read_frame<R, F>(
+        &mut self,
+        wait: Wait,
+        page_reply: bool,
+        mut f: F,
+    ) -> Result<Option<R>, ComError>
+    where
+        F: FnMut(Frame) -> Result<R, ComError>,
+    {
+        // the bytes are meant for the HMI code, not frames
+        if self.reparse {
+            return Err(ComError::ReparseModeActive);
+        }
+        let mut deadline = self.timeout.start();
+        loop {
+            let word = match self.read() {
+                Ok(word) => word,
+                Err(nb::Error::WouldBlock) => match wait {
+                    Wait::Poll => return Ok(None),
+                    Wait::Timeout => {
+                        if deadline.expired() {
+                            // the rest of the frame is dropped with the
+                            // late answer
+                            self.late = true;
+                            return Err(ComError::Timeout);
+                        }
+                        continue;
+                    }
+                },
+                Err(nb::Error::Other(err)) => return Err(err),
+            };
+
+            let frame = match self.parser.feed(word) {
+                Ok(Some(frame)) => frame,
+                Ok(None) => continue,
+                Err(err) => return Err(err),
+            };
+            let event = match frame {
+                Frame::PageId(_) if page_reply => None,
+                _ => NextionEvent::from_frame(&frame),
+            };
+            match event {
+                Some(event) => {
+                    self.queue_event(event);
+                    if wait == Wait::Poll {
+                        return Ok(None);
+                    }
+                }
+                None => {
+                    if let Frame::PageId(page) = frame {
+                        self.page = Some(page);
+                    }
+                    return f(frame).map(Some);
+                }
+            }
+        }
+    }
+
+    fn queue_event(&mut self, event: NextionEvent) {
+        match event {
+            NextionEvent::Sleep => self.asleep = true,
+            NextionEvent::Wake => self.asleep = false,
+            NextionEvent::Startup => {
+                self.asleep = false;
+                self.page = None;
+            }
+            NextionEvent::Page(page) => self.page = Some(page),
+            _ => {}
+        };
+        // the oldest event is dropped when the queue is full
+        if self.events.is_full() {
+            self.events.pop_front();
+        }
+        let _ = self.events.push_back(event);
+    }
+
+    /// Drops the answers received after their wait timed out, so they are
+    /// not taken for the answer of the next instruction. Events are queued.
+    fn drop_late_answers(&mut self) -> Result<(), ComError> {
+        loop {
+            let word = match self.read() {
+                Ok(word) => word,
+                Err(nb::Error::WouldBlock) => break,
+                Err(nb::Error::Other(err)) => return Err(err),
+            };
+            let event = match self.parser.feed(word) {
+                Ok(Some(frame)) => NextionEvent::from_frame(&frame),
+                Ok(None) | Err(_) => None,
+            };
+            if let Some(event) = event {
+                self.queue_event(event);
+            }
+        }
+        // the end of a partial answer is not expected anymore
+        self.parser.reset();
+        self.late = false;
+        Ok(())
+    }
 }
 
 impl<USART> Nextion<USART>
 where
     USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
 {
+    /// Sends `cmd` followed by the `FF FF FF` terminator and checks the
+    /// display answer according to the current [`AckMode`].
+    ///
+    /// With [`AckMode::OnFailure`] only an error frame that has already
+    /// arrived is reported as [`ComError::Device`].
     pub fn send_cmd(&mut self, cmd: &[u8]) -> Result<(), ComError> {
+        match self.send_query(cmd) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.check_ack()
+    }
+
+    /// Sends `cmd` followed by the `FF FF FF` terminator without waiting for
+    /// an acknowledgement, for instructions answered with data like `get`.
+    pub fn send_query(&mut self, cmd: &[u8]) -> Result<(), ComError> {
+        if self.reparse {
+            return Err(ComError::ReparseModeActive);
+        }
+        if self.late {
+            match self.drop_late_answers() {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+        }
         match self.get_peripheral().borrow_mut().bwrite_all(cmd) {
             Ok(_) => {}
             Err(_) => return Err(ComError::FailedWrite),
@@ -220,76 +684,391 @@ where
         Ok(())
     }
 
+    fn check_ack(&mut self) -> Result<(), ComError> {
+        let wait = match self.ack {
+            AckMode::Off => return Ok(()),
+            AckMode::OnSuccess | AckMode::Always => Wait::Timeout,
+            AckMode::OnFailure => Wait::Poll,
+        };
+
+        let res = self.next_frame(wait, |frame| match frame {
+            Frame::ReturnCode(NextionReturnCode::Success) => Ok(()),
+            Frame::ReturnCode(code) => Err(ComError::Device(code)),
+            _ => Err(ComError::InvalidEventFrame),
+        });
+        match res {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
     pub fn get_str(&mut self, buff: &mut [u8]) -> Result<u16, ComError> {
-        //Get string head
-        let mut len = match self.get_peripheral().borrow_mut().read() {
-            Ok(word) => {
-                if NextionCmd::CmdStringHead.into_u8() == word {
-                    0u16
-                } else {
-                    return Err(ComError::IvalidGetDataString);
-                }
+        let res = self.next_frame(Wait::Timeout, |frame| match frame {
+            Frame::String(data) => {
+                let len = data.len().min(buff.len());
+                buff[..len].copy_from_slice(&data[..len]);
+                Ok(len as u16)
             }
-            Err(_) => return Err(ComError::FailedRead),
-        };
-        let mut end = 0u8;
-        for buf in buff {
-            *buf = match self.get_peripheral().borrow_mut().read() {
-                Ok(word) => {
-                    if NextionCmd::CmdEnd.into_u8() == word {
-                        end += 1;
-                        if end >= 3 {
-                            break;
-                        }
-                        continue;
-                    }
-                    len += 1;
-                    word
-                }
-                Err(_) => return Err(ComError::FailedRead),
+            Frame::ReturnCode(code) if code != NextionReturnCode::Success => {
+                Err(ComError::Device(code))
             }
+            _ => Err(ComError::IvalidGetDataString),
+        });
+        match res {
+            Ok(Some(len)) => Ok(len),
+            Ok(None) => Err(ComError::IvalidGetDataString),
+            Err(err) => Err(err),
         }
-
-        Ok(len)
     }
 
     pub fn get_number<T>(&mut self, buff: &mut T) -> Result<(), ComError>
     where
         T: PrimInt,
     {
-        //Get number head
-        match self.get_peripheral().borrow_mut().read() {
-            Ok(word) => {
-                if NextionCmd::CmdNumberHead.into_u8() != word {
-                    return Err(ComError::IvalidGetDataNumber);
-                }
+        let res = self.next_frame(Wait::Timeout, |frame| match frame {
+            Frame::Number(val) => Ok(val),
+            Frame::ReturnCode(code) if code != NextionReturnCode::Success => {
+                Err(ComError::Device(code))
             }
-            Err(_) => return Err(ComError::FailedRead),
+            _ => Err(ComError::IvalidGetDataNumber),
+        });
+        let val = match res {
+            Ok(Some(val)) => val,
+            Ok(None) => return Err(ComError::IvalidGetDataNumber),
+            Err(err) => return Err(err),
+        };
+
+        *buff = match NumCast::from(val) {
+            Some(x) => x,
+            None => return Err(ComError::IvalidGetDataNumber),
+        };
+        Ok(())
+    }
+
+    /// Writes `data` as is, without the `FF FF FF` terminator.
+    pub fn send_raw(&mut self, data: &[u8]) -> Result<(), ComError> {
+        match self.get_peripheral().borrow_mut().bwrite_all(data) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedWrite),
         };
+        match self.get_peripheral().borrow_mut().bflush() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(ComError::FailedSendCmd),
+        }
+    }
 
-        let mut buffer = [0u8; 7];
-        for buf in &mut buffer {
-            *buf = match self.get_peripheral().borrow_mut().read() {
-                Ok(val) => val,
-                Err(_) => return Err(ComError::FailedRead),
+    /// Sends `cmd` starting a transparent transfer, like `addt`, then
+    /// streams `data` once the display is ready.
+    pub(crate) fn send_transparent(&mut self, cmd: &[u8], data: &[u8]) -> Result<(), ComError> {
+        match self.send_query(cmd) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        match self.wait_transparent(true) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        match self.send_raw(data) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.wait_transparent(false)
+    }
+
+    /// Waits for the `0xFE` ready frame, or the `0xFD` done frame.
+    fn wait_transparent(&mut self, ready: bool) -> Result<(), ComError> {
+        let res = self.next_frame(Wait::Timeout, |frame| match frame {
+            Frame::TransparentReady if ready => Ok(()),
+            Frame::TransparentDone if !ready => Ok(()),
+            Frame::ReturnCode(code) if code != NextionReturnCode::Success => {
+                Err(ComError::Device(code))
             }
+            _ => Err(ComError::InvalidEventFrame),
+        });
+        match res {
+            Ok(_) => Ok(()),
+            Err(err) => Err(err),
         }
+    }
 
-        if (NextionCmd::CmdEnd.into_u8() == buffer[4])
-            && (NextionCmd::CmdEnd.into_u8() == buffer[5])
-            && (NextionCmd::CmdEnd.into_u8() == buffer[6])
-        {
-            let val = (buffer[3] as i32) << 24
-                | (buffer[2] as i32) << 16
-                | (buffer[1] as i32) << 8
-                | (buffer[3] as i32);
-            *buff = match NumCast::from(val) {
-                Some(x) => x,
-                None => return Err(ComError::IvalidGetDataNumber),
+    /// Sends `name=value` for a system variable.
+    pub(crate) fn set_sys_var<T: Display>(&mut self, name: &str, value: T) -> Result<(), ComError> {
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "{}={}", name, value) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    /// Reads a system variable with `get name`.
+    pub(crate) fn get_sys_var<T: PrimInt>(&mut self, name: &str) -> Result<T, ComError> {
+        let mut cmd = String::<24>::new();
+        match write!(cmd, "get {}", name) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        match self.send_query(cmd.as_bytes()) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+
+        let mut buff: T = match NumCast::from(0) {
+            Some(x) => x,
+            None => return Err(ComError::FailedCreateNumberBuffer),
+        };
+        match self.get_number(&mut buff) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        Ok(buff)
+    }
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Reads one pending event from the display, if any, and dispatches touch
+    /// events to the matching component in `components`.
+    ///
+    /// Returns `Ok(None)` when no event is waiting.
+    pub fn poll_events<'l>(
+        &mut self,
+        components: &mut [&mut dyn TouchComponent<'l>],
+    ) -> Result<Option<NextionEvent>, ComError> {
+        if self.events.is_empty() {
+            // answers nobody waits for, only errors are reported
+            let res = self.next_frame(Wait::Poll, |frame| match frame {
+                Frame::ReturnCode(code) if code != NextionReturnCode::Success => {
+                    Err(ComError::Device(code))
+                }
+                _ => Ok(()),
+            });
+            match res {
+                Ok(_) => {}
+                Err(err) => return Err(err),
             };
-        } else {
-            return Err(ComError::IvalidGetDataNumber);
         }
-        Ok(())
+
+        let event = match self.events.pop_front() {
+            Some(x) => x,
+            None => return Ok(None),
+        };
+        Self::dispatch_event(&event, components);
+
+        Ok(Some(event))
+    }
+
+    fn dispatch_event<'l>(event: &NextionEvent, components: &mut [&mut dyn TouchComponent<'l>]) {
+        let (page, component, pressed) = match *event {
+            NextionEvent::Touch {
+                page,
+                component,
+                pressed,
+            } => (page, component, pressed),
+            _ => return,
+        };
+
+        for obj in components.iter_mut() {
+            if obj.get_page_id() == page && obj.get_component_id() == component {
+                if pressed {
+                    obj.call_on_click();
+                } else {
+                    obj.call_on_release();
+                }
+            }
+        }
+    }
+}
+
+/// A failed command is not answered in [`AckMode::OnSuccess`], waiting for
+/// every command needs a timeout.
+fn check_ack_timeout(ack: AckMode, timeout: ReadTimeout) -> Result<(), ComError> {
+    match (ack, timeout) {
+        (AckMode::OnSuccess | AckMode::Always, ReadTimeout::Infinite) => {
+            Err(ComError::InvalidValue)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Dereferences the device pointer held by pins.
+fn get_device<'a, USART>(device: *mut Nextion<USART>) -> &'a mut Nextion<USART> {
+    unsafe {
+        match device.as_mut() {
+            Some(val) => val,
+            None => panic!("Device is NULL"),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use heapless::Vec;
+
+    use super::*;
+
+    /// Serial port answering the bytes pushed in `rx`, the first bytes
+    /// written are kept in `tx`.
+    pub(crate) struct MockSerial {
+        pub(crate) rx: Deque<u8, 64>,
+        pub(crate) tx: Vec<u8, 64>,
+        /// Number of bytes written.
+        pub(crate) written: usize,
+        /// Bytes pushed to `rx` once `written` reaches their count.
+        pub(crate) replies: Deque<(usize, u8), 8>,
+        pub(crate) baud: u32,
+    }
+
+    impl MockSerial {
+        pub(crate) fn new() -> Self {
+            MockSerial {
+                rx: Deque::new(),
+                tx: Vec::new(),
+                written: 0,
+                replies: Deque::new(),
+                baud: 9600,
+            }
+        }
+    }
+
+    impl embedded_hal::serial::Read<u8> for MockSerial {
+        type Error = ();
+
+        fn read(&mut self) -> nb::Result<u8, ()> {
+            self.rx.pop_front().ok_or(nb::Error::WouldBlock)
+        }
+    }
+
+    impl embedded_hal::serial::Write<u8> for MockSerial {
+        type Error = ();
+
+        fn write(&mut self, word: u8) -> nb::Result<(), ()> {
+            let _ = self.tx.push(word);
+            self.written += 1;
+            while let Some(&(count, reply)) = self.replies.front() {
+                if count > self.written {
+                    break;
+                }
+                self.replies.pop_front();
+                self.rx.push_back(reply).unwrap();
+            }
+            Ok(())
+        }
+
+        fn flush(&mut self) -> nb::Result<(), ()> {
+            Ok(())
+        }
+    }
+
+    impl blocking::write::Default<u8> for MockSerial {}
+
+    pub(crate) fn nextion() -> Nextion<MockSerial> {
+        let mut nextion = Nextion::new(MockSerial::new());
+        nextion.set_read_timeout(ReadTimeout::Polls(3)).unwrap();
+        nextion
+    }
+
+    pub(crate) fn receive(nextion: &mut Nextion<MockSerial>, bytes: &[u8]) {
+        for word in bytes {
+            nextion.usart.borrow_mut().rx.push_back(*word).unwrap();
+        }
+    }
+
+    #[test]
+    fn late_answer_is_dropped() {
+        let mut nextion = nextion();
+        let mut val = 0i32;
+        nextion.send_query(b"get n0.val").unwrap();
+        receive(&mut nextion, &[0x71, 0x05]);
+        assert_eq!(nextion.get_number(&mut val), Err(ComError::Timeout));
+
+        receive(&mut nextion, &[0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF]);
+        receive(&mut nextion, &[0x66, 0x02, 0xFF, 0xFF, 0xFF]);
+        nextion.send_query(b"get n1.val").unwrap();
+        receive(
+            &mut nextion,
+            &[0x71, 0x07, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF],
+        );
+        assert_eq!(nextion.get_number(&mut val), Ok(()));
+        assert_eq!(val, 7);
+
+        // events received with the late answer are kept
+        assert_eq!(
+            nextion.poll_events(&mut []),
+            Ok(Some(NextionEvent::Page(2)))
+        );
+        assert_eq!(nextion.poll_events(&mut []), Ok(None));
+    }
+
+    #[test]
+    fn ack_modes_need_a_timeout() {
+        let mut nextion = Nextion::new(MockSerial::new());
+        assert_eq!(
+            nextion.set_ack_mode(AckMode::Always),
+            Err(ComError::InvalidValue)
+        );
+        nextion.set_read_timeout(ReadTimeout::Polls(3)).unwrap();
+        receive(&mut nextion, &[0x01, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(nextion.set_ack_mode(AckMode::Always), Ok(()));
+        assert_eq!(
+            nextion.set_read_timeout(ReadTimeout::Infinite),
+            Err(ComError::InvalidValue)
+        );
+    }
+
+    #[test]
+    fn unrequested_page_is_an_event() {
+        let mut nextion = nextion();
+        let mut val = 0i32;
+        nextion.send_query(b"get n0.val").unwrap();
+        receive(&mut nextion, &[0x66, 0x01, 0xFF, 0xFF, 0xFF]);
+        receive(
+            &mut nextion,
+            &[0x71, 0x05, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF],
+        );
+        assert_eq!(nextion.get_number(&mut val), Ok(()));
+        assert_eq!(val, 5);
+        assert_eq!(nextion.cached_page(), Some(1));
+
+        receive(&mut nextion, &[0x66, 0x03, 0xFF, 0xFF, 0xFF]);
+        assert_eq!(nextion.current_page(), Ok(3));
+        assert_eq!(nextion.cached_page(), Some(3));
+        assert_eq!(
+            nextion.poll_events(&mut []),
+            Ok(Some(NextionEvent::Page(1)))
+        );
+        assert_eq!(nextion.poll_events(&mut []), Ok(None));
+    }
+
+    #[test]
+    fn reparse_mode() {
+        let mut nextion = nextion();
+        let mut val = 0i32;
+        let mut reparse = nextion.enter_reparse_mode().unwrap();
+        reparse.write_raw(b"abc").unwrap();
+        drop(reparse);
+        assert!(!nextion.is_reparse_mode());
+
+        let reparse = nextion.enter_reparse_mode().unwrap();
+        reparse.exit().unwrap();
+        assert!(!nextion.is_reparse_mode());
+
+        // a leaked guard keeps the mode active
+        core::mem::forget(nextion.enter_reparse_mode().unwrap());
+        receive(
+            &mut nextion,
+            &[0x71, 0x05, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xFF],
+        );
+        assert_eq!(
+            nextion.get_number(&mut val),
+            Err(ComError::ReparseModeActive)
+        );
+        assert_eq!(
+            nextion.poll_events(&mut []),
+            Err(ComError::ReparseModeActive)
+        );
+        nextion.exit_reparse_mode().unwrap();
     }
 }
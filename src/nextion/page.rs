@@ -0,0 +1,116 @@
+use core::fmt::Write;
+
+use embedded_hal::blocking::serial as blocking;
+use heapless::String;
+
+use super::{frame::Frame, AckMode, ComError, Nextion, NextionReturnCode, Wait};
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Returns the page last reported by the display, `None` when unknown.
+    ///
+    /// The cache follows [`Nextion::goto_page`], [`Nextion::current_page`]
+    /// and `0x66` frames, so pages changed from the HMI are only seen when
+    /// their preinitialize event runs `sendme`.
+    pub fn cached_page(&self) -> Option<u8> {
+        self.page
+    }
+
+    /// Forgets the cached page, disabling the page check of components.
+    pub fn clear_cached_page(&mut self) {
+        self.page = None;
+    }
+
+    /// Loads page `page` with `page n`.
+    pub fn goto_page(&mut self, page: u8) -> Result<(), ComError> {
+        let mut cmd = String::<8>::new();
+        match write!(cmd, "page {}", page) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        match self.send_cmd(cmd.as_bytes()) {
+            Ok(_) => {}
+            Err(err) => {
+                self.page = None;
+                return Err(err);
+            }
+        };
+        self.page = Some(page);
+        Ok(())
+    }
+
+    /// Asks the display for the current page with `sendme`.
+    pub fn current_page(&mut self) -> Result<u8, ComError> {
+        match self.send_query(b"sendme") {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+
+        let res = self.read_frame(Wait::Timeout, true, |frame| match frame {
+            Frame::PageId(page) => Ok(page),
+            Frame::ReturnCode(code) if code != NextionReturnCode::Success => {
+                Err(ComError::Device(code))
+            }
+            _ => Err(ComError::IvalidGetDataNumber),
+        });
+        match res {
+            Ok(Some(page)) => Ok(page),
+            Ok(None) => Err(ComError::IvalidGetDataNumber),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Shows or hides every component of the page with `vis 255,x`.
+    pub fn set_page_visible(&mut self, visible: bool) -> Result<(), ComError> {
+        let mut cmd = String::<10>::new();
+        match write!(cmd, "vis 255,{}", visible as u8) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    /// Enables or disables touch on every component of the page with
+    /// `tsw 255,x`.
+    pub fn set_page_touch(&mut self, enable: bool) -> Result<(), ComError> {
+        let mut cmd = String::<10>::new();
+        match write!(cmd, "tsw 255,{}", enable as u8) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.send_cmd(cmd.as_bytes())
+    }
+
+    /// Stops redrawing components when their attributes change, with
+    /// `ref_stop`, until [`Nextion::ref_star`].
+    pub fn ref_stop(&mut self) -> Result<(), ComError> {
+        self.send_cmd(b"ref_stop")
+    }
+
+    /// Resumes redrawing components with `ref_star`, the changes made
+    /// meanwhile are drawn.
+    pub fn ref_star(&mut self) -> Result<(), ComError> {
+        self.send_cmd(b"ref_star")
+    }
+
+    /// Queues the next commands without executing them, with `com_stop`,
+    /// until [`Nextion::com_star`].
+    ///
+    /// Return codes of queued commands are only sent once they run, so
+    /// [`ComError::InvalidValue`] is returned in [`AckMode::OnSuccess`] and
+    /// [`AckMode::Always`].
+    pub fn com_stop(&mut self) -> Result<(), ComError> {
+        match self.ack {
+            AckMode::OnSuccess | AckMode::Always => return Err(ComError::InvalidValue),
+            AckMode::Off | AckMode::OnFailure => {}
+        };
+        self.send_cmd(b"com_stop")
+    }
+
+    /// Executes the queued commands with `com_star`.
+    pub fn com_star(&mut self) -> Result<(), ComError> {
+        self.send_cmd(b"com_star")
+    }
+}
@@ -0,0 +1,84 @@
+use embedded_hal::blocking::serial as blocking;
+
+use super::{ComError, Nextion};
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Returns `true` while the display is sleeping, as tracked from
+    /// [`Nextion::sleep`] and the auto sleep/wake events.
+    pub fn is_asleep(&self) -> bool {
+        self.asleep
+    }
+
+    /// Puts the display in sleep mode with `sleep=1`.
+    pub fn sleep(&mut self) -> Result<(), ComError> {
+        match self.set_sys_var("sleep", 1) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.asleep = true;
+        Ok(())
+    }
+
+    /// Wakes the display up with `sleep=0`.
+    pub fn wake(&mut self) -> Result<(), ComError> {
+        match self.set_sys_var("sleep", 0) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.asleep = false;
+        Ok(())
+    }
+
+    /// Sets `thsp`, the seconds without touch before sleeping. `0` disables
+    /// it, otherwise the range is 3 to 65535.
+    pub fn set_thsp(&mut self, secs: u16) -> Result<(), ComError> {
+        if secs != 0 && secs < 3 {
+            return Err(ComError::InvalidDataRange);
+        }
+        self.set_sys_var("thsp", secs)
+    }
+
+    pub fn get_thsp(&mut self) -> Result<u16, ComError> {
+        self.get_sys_var("thsp")
+    }
+
+    /// Sets `ussp`, the seconds without serial data before sleeping. `0`
+    /// disables it, otherwise the range is 3 to 65535.
+    pub fn set_ussp(&mut self, secs: u16) -> Result<(), ComError> {
+        if secs != 0 && secs < 3 {
+            return Err(ComError::InvalidDataRange);
+        }
+        self.set_sys_var("ussp", secs)
+    }
+
+    pub fn get_ussp(&mut self) -> Result<u16, ComError> {
+        self.get_sys_var("ussp")
+    }
+
+    /// Sets `thup`, wake up on touch.
+    pub fn set_thup(&mut self, enable: bool) -> Result<(), ComError> {
+        self.set_sys_var("thup", enable as u8)
+    }
+
+    pub fn get_thup(&mut self) -> Result<bool, ComError> {
+        match self.get_sys_var::<u8>("thup") {
+            Ok(x) => Ok(x == 1),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Sets `usup`, wake up on serial data.
+    pub fn set_usup(&mut self, enable: bool) -> Result<(), ComError> {
+        self.set_sys_var("usup", enable as u8)
+    }
+
+    pub fn get_usup(&mut self) -> Result<bool, ComError> {
+        match self.get_sys_var::<u8>("usup") {
+            Ok(x) => Ok(x == 1),
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use embedded_hal::blocking::serial as blocking;
+
+use super::{ComError, Nextion};
+
+/// Sequence leaving the active Protocol Reparse mode.
+pub const REPARSE_EXIT: &[u8] = b"DRAKJHSUYDGBNCJHGJKSHBDN";
+
+/// Display in active Protocol Reparse mode, returned by
+/// [`Nextion::enter_reparse_mode`].
+///
+/// Bytes are sent as is, without the `FF FF FF` terminator, for the HMI code
+/// to parse. While it exists the [`Nextion`] is borrowed, components, which
+/// only keep a pointer to it, get [`ComError::ReparseModeActive`].
+///
+/// The mode is left by [`ReparseMode::exit`], or when it is dropped, errors
+/// are then ignored.
+pub struct ReparseMode<'a, USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    nextion: &'a mut Nextion<USART>,
+}
+
+impl<'a, USART> ReparseMode<'a, USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Writes `data` as is.
+    pub fn write_raw(&mut self, data: &[u8]) -> Result<(), ComError> {
+        self.nextion.send_raw(data)
+    }
+
+    /// Reads one byte sent by the HMI code.
+    pub fn read_raw(&mut self) -> nb::Result<u8, ComError> {
+        self.nextion.read()
+    }
+
+    /// Leaves Protocol Reparse mode.
+    pub fn exit(self) -> Result<(), ComError> {
+        self.nextion.exit_reparse_mode()
+    }
+}
+
+impl<'a, USART> Drop for ReparseMode<'a, USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    fn drop(&mut self) {
+        // already left by `exit`
+        if self.nextion.reparse {
+            let _ = self.nextion.exit_reparse_mode();
+        }
+    }
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Returns `true` while Protocol Reparse mode is active.
+    pub fn is_reparse_mode(&self) -> bool {
+        self.reparse
+    }
+
+    /// Enters active Protocol Reparse mode with `recmod=1`.
+    pub fn enter_reparse_mode(&mut self) -> Result<ReparseMode<'_, USART>, ComError> {
+        // the display does not answer once the mode is active
+        match self.send_query(b"recmod=1") {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.parser.reset();
+        self.reparse = true;
+        Ok(ReparseMode { nextion: self })
+    }
+
+    /// Sends the exit sequence and leaves Protocol Reparse mode, also when
+    /// it was entered by another program or the HMI code.
+    pub fn exit_reparse_mode(&mut self) -> Result<(), ComError> {
+        match self.send_raw(REPARSE_EXIT) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.reparse = false;
+        self.clear_input()
+    }
+}
@@ -0,0 +1,297 @@
+use embedded_hal::blocking::serial as blocking;
+
+use super::{ComError, Nextion};
+
+const RTC_VARS: [&str; 7] = ["rtc0", "rtc1", "rtc2", "rtc3", "rtc4", "rtc5", "rtc6"];
+const SECS_PER_DAY: u32 = 86400;
+/// Days from 1970-01-01 to 2000-01-01.
+const DAYS_TO_2000: u32 = 10957;
+
+/// Date and time of the display RTC, `rtc0` to `rtc6`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct DateTime {
+    /// 2000 to 2099
+    pub year: u16,
+    /// 1 to 12
+    pub month: u8,
+    /// 1 to 31, depending on the month
+    pub day: u8,
+    /// 0 to 23
+    pub hour: u8,
+    /// 0 to 59
+    pub minute: u8,
+    /// 0 to 59
+    pub second: u8,
+    /// 0 (Sunday) to 6, computed by the display and not written
+    pub weekday: u8,
+}
+
+impl DateTime {
+    /// Returns `true` when every field is in range for the display RTC.
+    pub fn is_valid(&self) -> bool {
+        (2000..=2099).contains(&self.year)
+            && (1..=12).contains(&self.month)
+            && self.day >= 1
+            && self.day <= days_in_month(self.year, self.month)
+            && self.hour < 24
+            && self.minute < 60
+            && self.second < 60
+    }
+
+    /// Creates a [`DateTime`] from seconds since 1970-01-01 00:00:00.
+    ///
+    /// Times before 2000 return [`ComError::InvalidDataRange`].
+    pub fn from_unix(secs: u32) -> Result<Self, ComError> {
+        let days = secs / SECS_PER_DAY;
+        if days < DAYS_TO_2000 {
+            return Err(ComError::InvalidDataRange);
+        }
+        let time = secs % SECS_PER_DAY;
+        // 1970-01-01 was a Thursday
+        let weekday = ((days + 4) % 7) as u8;
+
+        let mut days = days - DAYS_TO_2000;
+        let mut year = 2000;
+        loop {
+            let len = match is_leap_year(year) {
+                true => 366,
+                false => 365,
+            };
+            if days < len {
+                break;
+            }
+            days -= len;
+            year += 1;
+        }
+        let mut month = 1;
+        loop {
+            let len = days_in_month(year, month) as u32;
+            if days < len {
+                break;
+            }
+            days -= len;
+            month += 1;
+        }
+
+        let dt = DateTime {
+            year,
+            month,
+            day: days as u8 + 1,
+            hour: (time / 3600) as u8,
+            minute: (time / 60 % 60) as u8,
+            second: (time % 60) as u8,
+            weekday,
+        };
+        match dt.is_valid() {
+            true => Ok(dt),
+            false => Err(ComError::InvalidDataRange),
+        }
+    }
+
+    /// Returns the seconds since 1970-01-01 00:00:00, the weekday is
+    /// ignored.
+    pub fn to_unix(&self) -> Result<u32, ComError> {
+        if !self.is_valid() {
+            return Err(ComError::InvalidDataRange);
+        }
+        let mut days = DAYS_TO_2000;
+        for year in 2000..self.year {
+            days += match is_leap_year(year) {
+                true => 366,
+                false => 365,
+            };
+        }
+        for month in 1..self.month {
+            days += days_in_month(self.year, month) as u32;
+        }
+        days += self.day as u32 - 1;
+
+        Ok(days * SECS_PER_DAY
+            + self.hour as u32 * 3600
+            + self.minute as u32 * 60
+            + self.second as u32)
+    }
+}
+
+// `is_multiple_of` needs Rust 1.87
+#[allow(clippy::manual_is_multiple_of)]
+fn is_leap_year(year: u16) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 => match is_leap_year(year) {
+            true => 29,
+            false => 28,
+        },
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Reads the RTC with `get rtc0` to `get rtc6`.
+    ///
+    /// The fields are read one by one, the read is repeated when the minute
+    /// changed meanwhile. Only Enhanced and Intelligent displays have an RTC.
+    pub fn rtc(&mut self) -> Result<DateTime, ComError> {
+        // the seconds, then `rtc0` to `rtc6`
+        let mut fields = [0u16; 8];
+        for _ in 0..2 {
+            for (field, name) in fields.iter_mut().zip(["rtc5"].iter().chain(&RTC_VARS)) {
+                match self.get_sys_var(name) {
+                    Ok(x) => *field = x,
+                    Err(err) => return Err(err),
+                };
+            }
+            // the seconds only go back when the minute changed
+            if fields[6] >= fields[0] {
+                break;
+            }
+        }
+
+        let dt = DateTime {
+            year: fields[1],
+            month: fields[2] as u8,
+            day: fields[3] as u8,
+            hour: fields[4] as u8,
+            minute: fields[5] as u8,
+            second: fields[6] as u8,
+            weekday: fields[7] as u8,
+        };
+        match dt.is_valid() {
+            true => Ok(dt),
+            false => Err(ComError::InvalidValue),
+        }
+    }
+
+    /// Writes `rtc0` to `rtc5`, the weekday is computed by the display.
+    pub fn set_rtc(&mut self, dt: &DateTime) -> Result<(), ComError> {
+        if !dt.is_valid() {
+            return Err(ComError::InvalidDataRange);
+        }
+        let fields = [
+            dt.year,
+            dt.month as u16,
+            dt.day as u16,
+            dt.hour as u16,
+            dt.minute as u16,
+            dt.second as u16,
+        ];
+        for (value, name) in fields.into_iter().zip(RTC_VARS) {
+            match self.set_sys_var(name, value) {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+        }
+        Ok(())
+    }
+
+    /// Reads the RTC as seconds since 1970-01-01 00:00:00.
+    pub fn rtc_unix(&mut self) -> Result<u32, ComError> {
+        match self.rtc() {
+            Ok(dt) => dt.to_unix(),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Sets the RTC from seconds since 1970-01-01 00:00:00.
+    pub fn set_rtc_unix(&mut self, secs: u32) -> Result<(), ComError> {
+        match DateTime::from_unix(secs) {
+            Ok(dt) => self.set_rtc(&dt),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: u16, month: u8, day: u8, hour: u8, minute: u8, second: u8) -> DateTime {
+        DateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            weekday: 0,
+        }
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2000));
+        assert!(is_leap_year(2024));
+        assert!(!is_leap_year(2023));
+        assert!(!is_leap_year(2100));
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2023, 2), 28);
+        assert!(date(2024, 2, 29, 0, 0, 0).is_valid());
+        assert!(!date(2023, 2, 29, 0, 0, 0).is_valid());
+    }
+
+    #[test]
+    fn from_unix() {
+        let dt = DateTime::from_unix(946_684_800).unwrap();
+        assert_eq!(
+            dt,
+            DateTime {
+                weekday: 6,
+                ..date(2000, 1, 1, 0, 0, 0)
+            }
+        );
+
+        let dt = DateTime::from_unix(1_709_251_199).unwrap();
+        assert_eq!(
+            dt,
+            DateTime {
+                weekday: 4,
+                ..date(2024, 2, 29, 23, 59, 59)
+            }
+        );
+
+        assert_eq!(
+            DateTime::from_unix(946_684_799),
+            Err(ComError::InvalidDataRange)
+        );
+        // 2100-01-01 00:00:00
+        assert_eq!(
+            DateTime::from_unix(4_102_444_800),
+            Err(ComError::InvalidDataRange)
+        );
+    }
+
+    #[test]
+    fn to_unix() {
+        assert_eq!(date(2000, 1, 1, 0, 0, 0).to_unix(), Ok(946_684_800));
+        assert_eq!(date(2024, 3, 1, 0, 0, 0).to_unix(), Ok(1_709_251_200));
+        assert_eq!(
+            date(2023, 2, 29, 0, 0, 0).to_unix(),
+            Err(ComError::InvalidDataRange)
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        // every day of 2000 to 2099 at a varying time
+        let mut secs = 946_684_800;
+        while secs < 4_102_444_800 {
+            let dt = DateTime::from_unix(secs).unwrap();
+            assert_eq!(dt.to_unix(), Ok(secs));
+            secs += SECS_PER_DAY + 3_661;
+        }
+        assert_eq!(
+            DateTime::from_unix(4_102_444_799).unwrap(),
+            DateTime {
+                weekday: 4,
+                ..date(2099, 12, 31, 23, 59, 59)
+            }
+        );
+    }
+}
@@ -0,0 +1,126 @@
+use core::convert::Infallible;
+
+use embedded_hal::blocking::serial as blocking;
+use heapless::spsc::{Consumer, Producer, Queue};
+
+use super::{ComError, Nextion};
+
+/// Receive half of the display link, meant to be owned by the USART RX
+/// interrupt handler.
+///
+/// Every byte read is pushed into the queue consumed by the [`Nextion`]
+/// created with [`Nextion::new_queued`].
+pub struct NextionRx<'a, RX, const N: usize> {
+    rx: RX,
+    producer: Producer<'a, u8, N>,
+}
+
+impl<'a, RX, const N: usize> NextionRx<'a, RX, N>
+where
+    RX: embedded_hal::serial::Read<u8>,
+{
+    /// Moves every byte available on the peripheral into the queue. Call it
+    /// from the RX interrupt.
+    ///
+    /// Returns [`ComError::RxOverrun`] when the queue is full, the byte is
+    /// dropped.
+    pub fn on_interrupt(&mut self) -> Result<(), ComError> {
+        loop {
+            let word = match self.rx.read() {
+                Ok(word) => word,
+                Err(nb::Error::WouldBlock) => return Ok(()),
+                Err(_) => return Err(ComError::FailedRead),
+            };
+            match self.push(word) {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+        }
+    }
+
+    /// Pushes one byte received by other means, like a DMA buffer.
+    pub fn push(&mut self, word: u8) -> Result<(), ComError> {
+        match self.producer.enqueue(word) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(ComError::RxOverrun),
+        }
+    }
+
+    /// Returns a mutable reference to the RX peripheral, to clear interrupt
+    /// flags.
+    pub fn get_peripheral(&mut self) -> &mut RX {
+        &mut self.rx
+    }
+}
+
+/// Serial link made of a transmit peripheral and the queue filled by
+/// [`NextionRx`].
+pub struct QueuedSerial<'a, TX, const N: usize> {
+    tx: TX,
+    consumer: Consumer<'a, u8, N>,
+}
+
+impl<'a, TX, const N: usize> QueuedSerial<'a, TX, N> {
+    /// Returns a mutable reference to the TX peripheral.
+    pub fn get_tx(&mut self) -> &mut TX {
+        &mut self.tx
+    }
+
+    /// Returns the number of bytes waiting in the queue.
+    pub fn len(&self) -> usize {
+        self.consumer.len()
+    }
+
+    /// Returns `true` when no byte is waiting in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a, TX, const N: usize> embedded_hal::serial::Read<u8> for QueuedSerial<'a, TX, N> {
+    type Error = Infallible;
+
+    fn read(&mut self) -> nb::Result<u8, Self::Error> {
+        match self.consumer.dequeue() {
+            Some(word) => Ok(word),
+            None => Err(nb::Error::WouldBlock),
+        }
+    }
+}
+
+impl<'a, TX, const N: usize> blocking::Write<u8> for QueuedSerial<'a, TX, N>
+where
+    TX: blocking::Write<u8>,
+{
+    type Error = TX::Error;
+
+    fn bwrite_all(&mut self, buffer: &[u8]) -> Result<(), Self::Error> {
+        self.tx.bwrite_all(buffer)
+    }
+
+    fn bflush(&mut self) -> Result<(), Self::Error> {
+        self.tx.bflush()
+    }
+}
+
+impl<'a, TX, const N: usize> Nextion<QueuedSerial<'a, TX, N>>
+where
+    TX: blocking::Write<u8>,
+{
+    /// Creates a new [`Nextion<X>`] reading from `queue`, and the
+    /// [`NextionRx`] half that fills it from `rx`.
+    pub fn new_queued<RX>(
+        tx: TX,
+        rx: RX,
+        queue: &'a mut Queue<u8, N>,
+    ) -> (Self, NextionRx<'a, RX, N>)
+    where
+        RX: embedded_hal::serial::Read<u8>,
+    {
+        let (producer, consumer) = queue.split();
+        (
+            Nextion::new(QueuedSerial { tx, consumer }),
+            NextionRx { rx, producer },
+        )
+    }
+}
@@ -0,0 +1,183 @@
+use core::fmt::Write;
+
+use embedded_hal::blocking::serial as blocking;
+use heapless::String;
+
+use super::{AckMode, ComError, Nextion};
+
+/// Typed access to the display system variables, returned by
+/// [`Nextion::system`].
+pub struct SystemVars<'a, USART> {
+    nextion: &'a mut Nextion<USART>,
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Returns the system variables accessor.
+    pub fn system(&mut self) -> SystemVars<'_, USART> {
+        SystemVars { nextion: self }
+    }
+}
+
+impl<'a, USART> SystemVars<'a, USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Sets `dim`, the backlight from 0 to 100 until power off.
+    pub fn set_dim(&mut self, dim: u8) -> Result<(), ComError> {
+        if dim > 100 {
+            return Err(ComError::InvalidDataRange);
+        }
+        self.nextion.set_sys_var("dim", dim)
+    }
+
+    pub fn get_dim(&mut self) -> Result<u8, ComError> {
+        self.nextion.get_sys_var("dim")
+    }
+
+    /// Sets `dims`, the backlight from 0 to 100 kept over power cycles.
+    pub fn set_dims(&mut self, dim: u8) -> Result<(), ComError> {
+        if dim > 100 {
+            return Err(ComError::InvalidDataRange);
+        }
+        self.nextion.set_sys_var("dims", dim)
+    }
+
+    pub fn get_dims(&mut self) -> Result<u8, ComError> {
+        self.nextion.get_sys_var("dims")
+    }
+
+    pub fn set_sys0(&mut self, value: i32) -> Result<(), ComError> {
+        self.nextion.set_sys_var("sys0", value)
+    }
+
+    pub fn get_sys0(&mut self) -> Result<i32, ComError> {
+        self.nextion.get_sys_var("sys0")
+    }
+
+    pub fn set_sys1(&mut self, value: i32) -> Result<(), ComError> {
+        self.nextion.set_sys_var("sys1", value)
+    }
+
+    pub fn get_sys1(&mut self) -> Result<i32, ComError> {
+        self.nextion.get_sys_var("sys1")
+    }
+
+    pub fn set_sys2(&mut self, value: i32) -> Result<(), ComError> {
+        self.nextion.set_sys_var("sys2", value)
+    }
+
+    pub fn get_sys2(&mut self) -> Result<i32, ComError> {
+        self.nextion.get_sys_var("sys2")
+    }
+
+    /// Reads `rand`, a random number in the range set with
+    /// [`SystemVars::randset`].
+    pub fn get_rand(&mut self) -> Result<i32, ComError> {
+        self.nextion.get_sys_var("rand")
+    }
+
+    /// Sets the range of `rand` with `randset min,max`.
+    pub fn randset(&mut self, min: i32, max: i32) -> Result<(), ComError> {
+        if min > max {
+            return Err(ComError::InvalidDataRange);
+        }
+        let mut cmd = String::<32>::new();
+        match write!(cmd, "randset {},{}", min, max) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        self.nextion.send_cmd(cmd.as_bytes())
+    }
+
+    /// Sets `bkcmd`, see [`Nextion::set_ack_mode`].
+    pub fn set_bkcmd(&mut self, ack: AckMode) -> Result<(), ComError> {
+        self.nextion.set_ack_mode(ack)
+    }
+
+    pub fn get_bkcmd(&mut self) -> Result<AckMode, ComError> {
+        match self.nextion.get_sys_var("bkcmd") {
+            Ok(x) => AckMode::from_u8(x),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Sets `delay`, pausing the display for `ms` milliseconds.
+    pub fn set_delay(&mut self, ms: u16) -> Result<(), ComError> {
+        self.nextion.set_sys_var("delay", ms)
+    }
+
+    /// Sets `sendxy`, see [`Nextion::set_sendxy`].
+    pub fn set_sendxy(&mut self, enable: bool) -> Result<(), ComError> {
+        self.nextion.set_sendxy(enable)
+    }
+
+    pub fn get_sendxy(&mut self) -> Result<bool, ComError> {
+        self.get_flag("sendxy")
+    }
+
+    /// Sets `thc`, the color of touch drawing.
+    pub fn set_thc(&mut self, color: u16) -> Result<(), ComError> {
+        self.nextion.set_sys_var("thc", color)
+    }
+
+    pub fn get_thc(&mut self) -> Result<u16, ComError> {
+        self.nextion.get_sys_var("thc")
+    }
+
+    /// Sets `thdra`, drawing where the panel is touched.
+    pub fn set_thdra(&mut self, enable: bool) -> Result<(), ComError> {
+        self.nextion.set_sys_var("thdra", enable as u8)
+    }
+
+    pub fn get_thdra(&mut self) -> Result<bool, ComError> {
+        self.get_flag("thdra")
+    }
+
+    /// Sets `ussp`, see [`Nextion::set_ussp`].
+    pub fn set_ussp(&mut self, secs: u16) -> Result<(), ComError> {
+        self.nextion.set_ussp(secs)
+    }
+
+    pub fn get_ussp(&mut self) -> Result<u16, ComError> {
+        self.nextion.get_ussp()
+    }
+
+    /// Sets `thsp`, see [`Nextion::set_thsp`].
+    pub fn set_thsp(&mut self, secs: u16) -> Result<(), ComError> {
+        self.nextion.set_thsp(secs)
+    }
+
+    pub fn get_thsp(&mut self) -> Result<u16, ComError> {
+        self.nextion.get_thsp()
+    }
+
+    /// Sets `lowpower`, deep sleep mode.
+    pub fn set_lowpower(&mut self, enable: bool) -> Result<(), ComError> {
+        self.nextion.set_sys_var("lowpower", enable as u8)
+    }
+
+    pub fn get_lowpower(&mut self) -> Result<bool, ComError> {
+        self.get_flag("lowpower")
+    }
+
+    /// Sets `wup`, the page loaded on wake up, `255` keeps the current page.
+    pub fn set_wup(&mut self, page: u8) -> Result<(), ComError> {
+        self.nextion.set_sys_var("wup", page)
+    }
+
+    pub fn get_wup(&mut self) -> Result<u8, ComError> {
+        self.nextion.get_sys_var("wup")
+    }
+
+    fn get_flag(&mut self, name: &str) -> Result<bool, ComError> {
+        match self.nextion.get_sys_var::<u8>(name) {
+            Ok(0) => Ok(false),
+            Ok(1) => Ok(true),
+            Ok(_) => Err(ComError::InvalidValue),
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use embedded_hal::blocking::serial as blocking;
+
+use super::{ComError, Nextion};
+
+/// Touch coordinate sent with `sendxy=1`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TouchPoint {
+    pub x: u16,
+    pub y: u16,
+    pub pressed: bool,
+}
+
+/// Gesture recognized by [`GestureRecognizer`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum Gesture {
+    Tap { x: u16, y: u16 },
+    LongPress { x: u16, y: u16 },
+    SwipeLeft,
+    SwipeRight,
+    SwipeUp,
+    SwipeDown,
+}
+
+/// Recognizes gestures from the touch points of press and release.
+///
+/// Time is given in ticks of any unit, the long press duration uses the same
+/// unit.
+#[derive(Clone)]
+pub struct GestureRecognizer {
+    swipe_distance: u16,
+    tap_distance: u16,
+    long_press_ticks: u32,
+    start: Option<(TouchPoint, u32)>,
+}
+
+impl GestureRecognizer {
+    /// Creates a new [`GestureRecognizer`], a press held at least
+    /// `long_press_ticks` is a long press.
+    pub fn new(long_press_ticks: u32) -> Self {
+        GestureRecognizer {
+            swipe_distance: 40,
+            tap_distance: 10,
+            long_press_ticks,
+            start: None,
+        }
+    }
+
+    /// Sets the minimum distance in pixels of a swipe, 40 by default.
+    pub fn set_swipe_distance(&mut self, distance: u16) {
+        self.swipe_distance = distance;
+    }
+
+    /// Sets the maximum distance in pixels a tap may move, 10 by default.
+    pub fn set_tap_distance(&mut self, distance: u16) {
+        self.tap_distance = distance;
+    }
+
+    /// Drops the press in progress.
+    pub fn reset(&mut self) {
+        self.start = None;
+    }
+
+    /// Feeds a touch point received at `now`, returns the gesture ended by a
+    /// release.
+    pub fn feed(&mut self, point: TouchPoint, now: u32) -> Option<Gesture> {
+        if point.pressed {
+            if self.start.is_none() {
+                self.start = Some((point, now));
+            }
+            return None;
+        }
+
+        let (start, time) = self.start.take()?;
+        let dx = point.x as i32 - start.x as i32;
+        let dy = point.y as i32 - start.y as i32;
+        let swipe = self.swipe_distance as i32;
+
+        if dx.abs() >= dy.abs() && dx.abs() >= swipe {
+            return match dx < 0 {
+                true => Some(Gesture::SwipeLeft),
+                false => Some(Gesture::SwipeRight),
+            };
+        }
+        if dy.abs() > dx.abs() && dy.abs() >= swipe {
+            return match dy < 0 {
+                true => Some(Gesture::SwipeUp),
+                false => Some(Gesture::SwipeDown),
+            };
+        }
+
+        let tap = self.tap_distance as i32;
+        if dx.abs() > tap || dy.abs() > tap {
+            return None;
+        }
+        match now.wrapping_sub(time) >= self.long_press_ticks {
+            true => Some(Gesture::LongPress {
+                x: start.x,
+                y: start.y,
+            }),
+            false => Some(Gesture::Tap {
+                x: start.x,
+                y: start.y,
+            }),
+        }
+    }
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8>,
+{
+    /// Sets `sendxy`, the display then sends [`TouchPoint`] events on press
+    /// and release.
+    pub fn set_sendxy(&mut self, enable: bool) -> Result<(), ComError> {
+        self.set_sys_var("sendxy", enable as u8)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: u16, y: u16, pressed: bool) -> TouchPoint {
+        TouchPoint { x, y, pressed }
+    }
+
+    /// Feeds a press at `from` and a release at `to` `ticks` later.
+    fn gesture(
+        recognizer: &mut GestureRecognizer,
+        from: (u16, u16),
+        to: (u16, u16),
+        ticks: u32,
+    ) -> Option<Gesture> {
+        assert_eq!(recognizer.feed(point(from.0, from.1, true), 1000), None);
+        recognizer.feed(point(to.0, to.1, false), 1000 + ticks)
+    }
+
+    #[test]
+    fn tap_and_long_press() {
+        let mut recognizer = GestureRecognizer::new(500);
+        let tap = Some(Gesture::Tap { x: 100, y: 100 });
+        let long_press = Some(Gesture::LongPress { x: 100, y: 100 });
+        assert_eq!(gesture(&mut recognizer, (100, 100), (100, 100), 0), tap);
+        assert_eq!(gesture(&mut recognizer, (100, 100), (110, 90), 499), tap);
+        assert_eq!(
+            gesture(&mut recognizer, (100, 100), (100, 100), 500),
+            long_press
+        );
+        // more than the tap distance but less than a swipe
+        assert_eq!(gesture(&mut recognizer, (100, 100), (111, 100), 0), None);
+        assert_eq!(gesture(&mut recognizer, (100, 100), (100, 139), 0), None);
+    }
+
+    #[test]
+    fn swipes() {
+        let mut recognizer = GestureRecognizer::new(500);
+        let swipe_left = Some(Gesture::SwipeLeft);
+        assert_eq!(
+            gesture(&mut recognizer, (100, 100), (60, 100), 0),
+            swipe_left
+        );
+        assert_eq!(
+            gesture(&mut recognizer, (100, 100), (140, 100), 0),
+            Some(Gesture::SwipeRight)
+        );
+        assert_eq!(
+            gesture(&mut recognizer, (100, 100), (100, 60), 0),
+            Some(Gesture::SwipeUp)
+        );
+        assert_eq!(
+            gesture(&mut recognizer, (100, 100), (100, 140), 2000),
+            Some(Gesture::SwipeDown)
+        );
+        // the longer axis wins, horizontal on a tie
+        assert_eq!(
+            gesture(&mut recognizer, (100, 100), (130, 150), 0),
+            Some(Gesture::SwipeDown)
+        );
+        assert_eq!(
+            gesture(&mut recognizer, (100, 100), (50, 50), 0),
+            swipe_left
+        );
+    }
+
+    #[test]
+    fn thresholds() {
+        let mut recognizer = GestureRecognizer::new(500);
+        recognizer.set_swipe_distance(20);
+        recognizer.set_tap_distance(2);
+        assert_eq!(
+            gesture(&mut recognizer, (100, 100), (120, 100), 0),
+            Some(Gesture::SwipeRight)
+        );
+        assert_eq!(gesture(&mut recognizer, (100, 100), (103, 100), 0), None);
+        assert_eq!(
+            gesture(&mut recognizer, (100, 100), (102, 98), 0),
+            Some(Gesture::Tap { x: 100, y: 100 })
+        );
+    }
+
+    #[test]
+    fn press_tracking() {
+        let mut recognizer = GestureRecognizer::new(500);
+        // release without press
+        assert_eq!(recognizer.feed(point(100, 100, false), 0), None);
+
+        // the first press point is kept while the finger moves
+        assert_eq!(recognizer.feed(point(100, 100, true), 0), None);
+        assert_eq!(recognizer.feed(point(130, 100, true), 10), None);
+        assert_eq!(
+            recognizer.feed(point(140, 100, false), 20),
+            Some(Gesture::SwipeRight)
+        );
+
+        assert_eq!(recognizer.feed(point(100, 100, true), 0), None);
+        recognizer.reset();
+        assert_eq!(recognizer.feed(point(100, 100, false), 10), None);
+
+        // tick counter wrapping
+        assert_eq!(recognizer.feed(point(100, 100, true), u32::MAX - 100), None);
+        assert_eq!(
+            recognizer.feed(point(100, 100, false), 400),
+            Some(Gesture::LongPress { x: 100, y: 100 })
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use core::fmt::Write;
+
+use embedded_hal::blocking::serial as blocking;
+use heapless::String;
+
+use super::{
+    baud::{BaudConfigurable, STANDARD_BAUDS},
+    ComError, Nextion,
+};
+
+/// Number of bytes acknowledged by the display during an upload.
+pub const UPLOAD_CHUNK_LEN: u32 = 4096;
+
+/// Byte sent by the display when it is ready for the next chunk.
+const UPLOAD_ACK: u8 = 0x05;
+
+/// Source of a TFT file, like external flash, an SD card or a byte slice.
+pub trait TftSource {
+    /// Returns the file size in bytes.
+    fn size(&self) -> u32;
+    /// Reads the next bytes into `buff`, returns the number of bytes read,
+    /// `0` at the end of the file.
+    fn read(&mut self, buff: &mut [u8]) -> Result<usize, ComError>;
+}
+
+/// [`TftSource`] over a byte slice.
+pub struct SliceSource<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> SliceSource<'a> {
+    /// Creates a new [`SliceSource`].
+    pub fn new(data: &'a [u8]) -> Self {
+        SliceSource { data, pos: 0 }
+    }
+}
+
+impl<'a> TftSource for SliceSource<'a> {
+    fn size(&self) -> u32 {
+        self.data.len() as u32
+    }
+
+    fn read(&mut self, buff: &mut [u8]) -> Result<usize, ComError> {
+        let len = buff.len().min(self.data.len() - self.pos);
+        buff[..len].copy_from_slice(&self.data[self.pos..self.pos + len]);
+        self.pos += len;
+        Ok(len)
+    }
+}
+
+impl<USART> Nextion<USART>
+where
+    USART: embedded_hal::serial::Read<u8> + blocking::Write<u8> + BaudConfigurable,
+{
+    /// Uploads a TFT file with `whmi-wri`, switching the link to `baud`.
+    ///
+    /// `progress` is called with the bytes sent and the file size after each
+    /// acknowledged chunk. The display reboots with the new HMI once the
+    /// upload is complete, the peripheral is left at `baud`.
+    pub fn upload_tft<S>(
+        &mut self,
+        source: &mut S,
+        baud: u32,
+        progress: &mut dyn FnMut(u32, u32),
+    ) -> Result<(), ComError>
+    where
+        S: TftSource,
+    {
+        if !STANDARD_BAUDS.contains(&baud) {
+            return Err(ComError::InvalidDataRange);
+        }
+        let size = source.size();
+
+        // terminate anything left in the display input buffer
+        match self.send_query(b"") {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        let mut cmd = String::<32>::new();
+        match write!(cmd, "whmi-wri {},{},0", size, baud) {
+            Ok(_) => {}
+            Err(_) => return Err(ComError::FailedCreateCommand),
+        };
+        match self.send_query(cmd.as_bytes()) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        match self.get_peripheral().borrow_mut().set_baud_rate(baud) {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+        self.parser.reset();
+        match self.wait_upload_ack() {
+            Ok(_) => {}
+            Err(err) => return Err(err),
+        };
+
+        let mut buff = [0u8; 256];
+        let mut sent = 0u32;
+        while sent < size {
+            // one chunk is sent in pieces to keep the buffer small
+            let end = (sent + UPLOAD_CHUNK_LEN).min(size);
+            while sent < end {
+                let len = (buff.len() as u32).min(end - sent) as usize;
+                let len = match source.read(&mut buff[..len]) {
+                    Ok(0) => return Err(ComError::FailedRead),
+                    Ok(x) => x,
+                    Err(err) => return Err(err),
+                };
+                match self.send_raw(&buff[..len]) {
+                    Ok(_) => {}
+                    Err(err) => return Err(err),
+                };
+                sent += len as u32;
+            }
+
+            match self.wait_upload_ack() {
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+            progress(sent, size);
+        }
+        Ok(())
+    }
+
+    fn wait_upload_ack(&mut self) -> Result<(), ComError> {
+        loop {
+            match self.read_raw() {
+                Ok(UPLOAD_ACK) => return Ok(()),
+                Ok(_) => {}
+                Err(err) => return Err(err),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use heapless::Vec;
+
+    use super::*;
+    use crate::nextion::tests::{nextion, MockSerial};
+
+    const SIZE: usize = 2 * UPLOAD_CHUNK_LEN as usize + 100;
+    const COMMAND: &[u8] = b"\xFF\xFF\xFFwhmi-wri 8292,115200,0\xFF\xFF\xFF";
+
+    impl BaudConfigurable for MockSerial {
+        fn set_baud_rate(&mut self, baud: u32) -> Result<(), ComError> {
+            self.baud = baud;
+            Ok(())
+        }
+    }
+
+    /// Uploads `SIZE` bytes, the display acknowledging after `acks` bytes of
+    /// the file.
+    fn upload(acks: &[usize], progress: &mut Vec<(u32, u32), 4>) -> Result<(), ComError> {
+        let mut nextion = nextion();
+        for count in acks {
+            let reply = (COMMAND.len() + count, UPLOAD_ACK);
+            nextion.usart.borrow_mut().replies.push_back(reply).unwrap();
+        }
+        let data = [0xA5; SIZE];
+        let res = nextion.upload_tft(&mut SliceSource::new(&data), 115200, &mut |sent, size| {
+            progress.push((sent, size)).unwrap();
+        });
+
+        let usart = nextion.usart.borrow();
+        assert_eq!(&usart.tx[..COMMAND.len()], COMMAND);
+        assert_eq!(usart.baud, 115200);
+        res
+    }
+
+    #[test]
+    fn chunks_wait_for_ack() {
+        let mut progress = Vec::new();
+        assert_eq!(upload(&[0, 4096, 8192, SIZE], &mut progress), Ok(()));
+        assert_eq!(progress, [(4096, 8292), (8192, 8292), (8292, 8292)]);
+    }
+
+    #[test]
+    fn missing_ack() {
+        let mut progress = Vec::new();
+        assert_eq!(upload(&[0, 4096], &mut progress), Err(ComError::Timeout));
+        assert_eq!(progress, [(4096, 8292)]);
+
+        // the display never gets ready
+        progress.clear();
+        assert_eq!(upload(&[], &mut progress), Err(ComError::Timeout));
+        assert!(progress.is_empty());
+    }
+
+    #[test]
+    fn invalid_baud() {
+        let mut nextion = nextion();
+        let res = nextion.upload_tft(&mut SliceSource::new(&[0; 16]), 1200, &mut |_, _| {});
+        assert_eq!(res, Err(ComError::InvalidDataRange));
+        assert!(nextion.usart.borrow().tx.is_empty());
+    }
+}